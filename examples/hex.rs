@@ -1,3 +1,5 @@
+use make_colors::colorspace::ColorSpace;
+use make_colors::gradient::GradientOptions;
 use make_colors::*;
 
 fn main() {
@@ -45,16 +47,17 @@ fn main() {
     }
 
     println!("\n🌈 Gradient Test (Red to Blue):");
-    for i in 0..=20 {
-        let r = 255 - (i * 12);
-        let g = 0;
-        let b = i * 12;
-        let hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
-        let colored = make_colors_hex("████", &hex, None)
-            .unwrap_or_else(|_| "█".to_string());
-        print!("{}", colored);
+    let red_blue = [Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)];
+    for space in [ColorSpace::Rgb, ColorSpace::Hsl, ColorSpace::Oklab] {
+        let options = GradientOptions::default().space(space);
+        println!("{} {:?}", gradient(&"█".repeat(84), &red_blue, &options), space);
+    }
+    println!("{}", gradient::rainbow("  ★ Multi-stop rainbow across 日本語 and emoji 🎉 ★  "));
+
+    println!("\n🏷 Named Colors (CSS/X11):");
+    for name in ["darkorchid", "seagreen", "royalblue", "peachpuff", "rebeccapurple", "navyblue"] {
+        println!("{}", make_colors(&format!("  ████ {}", name), name, None));
     }
-    println!();
 
     println!("\n🎨 Rainbow Spectrum:");
     let rainbow = vec![
@@ -0,0 +1,22 @@
+use make_colors::export::{self, SvgOptions};
+use make_colors::*;
+
+// Renders basic.rs-style output as an SVG terminal screenshot:
+//   cargo run --example screenshot > screenshots/basic_example.svg
+fn main() {
+    // Always emit escape codes, even when stdout is redirected to a file
+    support::set_color_level(Some(ColorLevel::TrueColor));
+
+    let lines = [
+        make_colors("Simple red text", "red", None),
+        make_colors("White on blue", "white", Some("blue")),
+        make_colors("Light green", "lightgreen", None),
+        make_colors_hex("Orange (#FF8800)", "#FF8800", None).unwrap(),
+        make_colors_rgb("Custom RGB", (255, 100, 50), None),
+        make_colors_with_attrs("Bold underline", "cyan", None, &["bold", "underline"]),
+        markup::render("[bold white on red] ERROR [/] disk full").unwrap(),
+    ];
+
+    let options = SvgOptions::default().title("make_colors - basic example");
+    print!("{}", export::to_svg(&lines.join("\n"), &options));
+}
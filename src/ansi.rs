@@ -0,0 +1,315 @@
+// File: src\ansi.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: ANSI escape codes, escape stripping and visible-width helpers.
+// License: MIT
+
+//! ANSI color codes for standard colors, plus helpers for text that already
+//! contains escape sequences.
+//!
+//! [`strip`] removes CSI (including SGR) and OSC sequences, [`visible_width`]
+//! measures the columns a string takes in a terminal and [`truncate_visible`]
+//! shortens it to a number of columns without breaking its styling.
+//!
+//! # Examples
+//! ```
+//! use make_colors::ansi;
+//!
+//! let text = format!("{}日本{} ok", ansi::RED, ansi::RESET);
+//! assert_eq!(ansi::strip(&text), "日本 ok");
+//! assert_eq!(ansi::visible_width(&text), 7);
+//! assert_eq!(ansi::truncate_visible(&text, 3, "…"), "\x1b[31m日…\x1b[0m");
+//! ```
+
+use std::borrow::Cow;
+
+use crate::width;
+
+// Foreground colors
+pub const BLACK: &str = "\x1b[30m";
+pub const RED: &str = "\x1b[31m";
+pub const GREEN: &str = "\x1b[32m";
+pub const YELLOW: &str = "\x1b[33m";
+pub const BLUE: &str = "\x1b[34m";
+pub const MAGENTA: &str = "\x1b[35m";
+pub const CYAN: &str = "\x1b[36m";
+pub const WHITE: &str = "\x1b[37m";
+
+// Light colors
+pub const LIGHT_BLACK: &str = "\x1b[90m";
+pub const LIGHT_RED: &str = "\x1b[91m";
+pub const LIGHT_GREEN: &str = "\x1b[92m";
+pub const LIGHT_YELLOW: &str = "\x1b[93m";
+pub const LIGHT_BLUE: &str = "\x1b[94m";
+pub const LIGHT_MAGENTA: &str = "\x1b[95m";
+pub const LIGHT_CYAN: &str = "\x1b[96m";
+pub const LIGHT_WHITE: &str = "\x1b[97m";
+
+// Background colors
+pub const BG_BLACK: &str = "\x1b[40m";
+pub const BG_RED: &str = "\x1b[41m";
+pub const BG_GREEN: &str = "\x1b[42m";
+pub const BG_YELLOW: &str = "\x1b[43m";
+pub const BG_BLUE: &str = "\x1b[44m";
+pub const BG_MAGENTA: &str = "\x1b[45m";
+pub const BG_CYAN: &str = "\x1b[46m";
+pub const BG_WHITE: &str = "\x1b[47m";
+
+// Light background colors
+pub const BG_LIGHT_BLACK: &str = "\x1b[100m";
+pub const BG_LIGHT_RED: &str = "\x1b[101m";
+pub const BG_LIGHT_GREEN: &str = "\x1b[102m";
+pub const BG_LIGHT_YELLOW: &str = "\x1b[103m";
+pub const BG_LIGHT_BLUE: &str = "\x1b[104m";
+pub const BG_LIGHT_MAGENTA: &str = "\x1b[105m";
+pub const BG_LIGHT_CYAN: &str = "\x1b[106m";
+pub const BG_LIGHT_WHITE: &str = "\x1b[107m";
+
+// Default colors
+pub const DEFAULT: &str = "\x1b[39m";
+pub const BG_DEFAULT: &str = "\x1b[49m";
+
+// Reset and attributes
+pub const RESET: &str = "\x1b[0m";
+pub const BOLD: &str = "\x1b[1m";
+pub const DIM: &str = "\x1b[2m";
+pub const ITALIC: &str = "\x1b[3m";
+pub const UNDERLINE: &str = "\x1b[4m";
+pub const DOUBLE_UNDERLINE: &str = "\x1b[21m";
+pub const CURLY_UNDERLINE: &str = "\x1b[4:3m";
+pub const DOTTED_UNDERLINE: &str = "\x1b[4:4m";
+pub const DASHED_UNDERLINE: &str = "\x1b[4:5m";
+pub const BLINK: &str = "\x1b[5m";
+pub const RAPID_BLINK: &str = "\x1b[6m";
+pub const REVERSE: &str = "\x1b[7m";
+pub const HIDDEN: &str = "\x1b[8m";
+pub const STRIKETHROUGH: &str = "\x1b[9m";
+pub const FRAMED: &str = "\x1b[51m";
+pub const ENCIRCLED: &str = "\x1b[52m";
+pub const OVERLINE: &str = "\x1b[53m";
+
+const ESC: char = '\x1b';
+
+/// A piece of text that is either printable or a single escape sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    Escape(&'a str),
+}
+
+/// Split text into printable runs and escape sequences
+///
+/// Unterminated sequences at the end of the input are returned as escapes,
+/// so they never leak into the printable text.
+pub(crate) fn segments(text: &str) -> Segments<'_> {
+    Segments { text }
+}
+
+pub(crate) struct Segments<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        let (segment, len) = if self.text.starts_with(ESC) {
+            let len = escape_len(self.text);
+            (Segment::Escape(&self.text[..len]), len)
+        } else {
+            let len = self.text.find(ESC).unwrap_or(self.text.len());
+            (Segment::Text(&self.text[..len]), len)
+        };
+        self.text = &self.text[len..];
+        Some(segment)
+    }
+}
+
+/// Byte length of the escape sequence at the start of `s`
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        // CSI: parameter and intermediate bytes, then one final byte
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7E).contains(b))
+            .map_or(bytes.len(), |i| i + 3),
+        // OSC: terminated by BEL or ST (ESC \)
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1B if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        }
+        // Two-character escapes such as ESC 7 or ESC \
+        Some(b) if b.is_ascii() => 2,
+        _ => 1,
+    }
+}
+
+/// Whether `escape` is an SGR sequence that turns every style off
+pub(crate) fn is_reset(escape: &str) -> bool {
+    sgr_params(escape).is_some_and(|params| params.split(';').all(|p| p.is_empty() || p == "0"))
+}
+
+/// Parameters of an SGR sequence (`"1;31"` for `\x1b[1;31m`)
+pub(crate) fn sgr_params(escape: &str) -> Option<&str> {
+    escape.strip_prefix("\x1b[")?.strip_suffix('m')
+}
+
+/// Remove every escape sequence from `text`
+///
+/// Text without escapes is returned without allocating.
+///
+/// # Examples
+/// ```
+/// use make_colors::ansi::strip;
+///
+/// assert_eq!(strip("\x1b[1;31mError\x1b[0m: \x1b]8;;https://example.com\x07link\x1b]8;;\x07"), "Error: link");
+/// ```
+pub fn strip(text: &str) -> Cow<'_, str> {
+    if !text.contains(ESC) {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(
+        segments(text)
+            .filter_map(|segment| match segment {
+                Segment::Text(text) => Some(text),
+                Segment::Escape(_) => None,
+            })
+            .collect(),
+    )
+}
+
+/// Number of terminal columns `text` takes, ignoring escape sequences
+///
+/// Width is counted per grapheme cluster: East Asian wide characters and
+/// emoji take two columns, combining marks and other zero-width characters
+/// take none.
+///
+/// # Examples
+/// ```
+/// use make_colors::ansi::visible_width;
+///
+/// assert_eq!(visible_width("\x1b[32mok\x1b[0m"), 2);
+/// assert_eq!(visible_width("カタカナ"), 8);
+/// assert_eq!(visible_width("cafe\u{301}"), 4);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    segments(text)
+        .map(|segment| match segment {
+            Segment::Text(text) => width::text_width(text),
+            Segment::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// Shorten `text` to at most `width` columns, ending with `ellipsis`
+///
+/// Escape sequences before the cut are kept, the ellipsis is written in the
+/// style active at the cut and a reset is appended if a style is still on.
+/// Text that already fits is returned unchanged.
+///
+/// # Examples
+/// ```
+/// use make_colors::ansi::truncate_visible;
+///
+/// assert_eq!(truncate_visible("\x1b[1mHello world\x1b[0m", 8, "..."), "\x1b[1mHello...\x1b[0m");
+/// assert_eq!(truncate_visible("short", 8, "..."), "short");
+/// ```
+pub fn truncate_visible(text: &str, width: usize, ellipsis: &str) -> String {
+    if visible_width(text) <= width {
+        return text.to_string();
+    }
+
+    let ellipsis_width = visible_width(ellipsis);
+    let (ellipsis, budget) = if ellipsis_width <= width {
+        (Cow::Borrowed(ellipsis), width - ellipsis_width)
+    } else {
+        (Cow::Owned(truncate_visible(ellipsis, width, "")), 0)
+    };
+
+    let mut result = String::new();
+    let mut used = 0;
+    let mut active = false;
+
+    'outer: for segment in segments(text) {
+        if used == budget {
+            break;
+        }
+        match segment {
+            Segment::Escape(escape) => {
+                if sgr_params(escape).is_some() {
+                    active = !is_reset(escape);
+                }
+                result.push_str(escape);
+            }
+            Segment::Text(text) => {
+                for grapheme in width::graphemes(text) {
+                    let grapheme_width = width::grapheme_width(grapheme);
+                    if used + grapheme_width > budget {
+                        break 'outer;
+                    }
+                    used += grapheme_width;
+                    result.push_str(grapheme);
+                }
+            }
+        }
+    }
+
+    result.push_str(&ellipsis);
+    if active {
+        result.push_str(RESET);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        let text = "a\x1b[1;38;2;1;2;3mb\x1b]0;title\x1b\\c\x1b[";
+        let parts: Vec<Segment> = segments(text).collect();
+        assert_eq!(
+            parts,
+            [
+                Segment::Text("a"),
+                Segment::Escape("\x1b[1;38;2;1;2;3m"),
+                Segment::Text("b"),
+                Segment::Escape("\x1b]0;title\x1b\\"),
+                Segment::Text("c"),
+                Segment::Escape("\x1b["),
+            ]
+        );
+        assert!(is_reset("\x1b[m") && is_reset("\x1b[0m") && !is_reset("\x1b[01m"));
+    }
+
+    #[test]
+    fn test_strip() {
+        assert!(matches!(strip("plain"), Cow::Borrowed("plain")));
+        assert_eq!(strip("\x1b[2J\x1b[Hx\x1b[38;5;208my\x1b[0m"), "xy");
+        assert_eq!(strip("tail\x1b]8;;unterminated"), "tail");
+    }
+
+    #[test]
+    fn test_truncate_visible() {
+        let text = format!("{}{}red{} plain", BOLD, RED, RESET);
+        assert_eq!(truncate_visible(&text, 4, "…"), format!("{}{}red…", BOLD, RED) + RESET);
+        assert_eq!(truncate_visible(&text, 6, "…"), format!("{}{}red{} p…", BOLD, RED, RESET));
+        assert_eq!(truncate_visible("日本語テキスト", 5, "…"), "日本…");
+        assert_eq!(truncate_visible("abcdef", 2, "..."), "..");
+        assert_eq!(visible_width(&truncate_visible("👍🏽👍🏽👍🏽", 5, "")), 4);
+    }
+}
@@ -0,0 +1,331 @@
+// File: src\attributes.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Typed text attribute sets for make_colors.
+// License: MIT
+
+//! Typed text attributes.
+//!
+//! [`Attributes`] is a small bitset of SGR text attributes. Sets can be
+//! combined with `|`, `&` and `-`, parsed from the attribute names used
+//! throughout the crate and encoded to ANSI escape codes.
+//!
+//! # Examples
+//! ```
+//! use make_colors::Attributes;
+//!
+//! let attrs = Attributes::BOLD | Attributes::UNDERLINE;
+//! assert!(attrs.contains(Attributes::BOLD));
+//! assert_eq!(attrs.to_string(), "bold underline");
+//!
+//! let parsed: Attributes = "bold, curly_underline".parse().unwrap();
+//! assert_eq!(parsed, Attributes::BOLD | Attributes::CURLY_UNDERLINE);
+//! assert_eq!(parsed.to_ansi(), "\x1b[1m\x1b[4:3m");
+//! ```
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::{ansi, MakeColorsError};
+
+/// A set of text attributes
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Attributes(u32);
+
+/// Canonical name, escape code and SGR parameter of every attribute, in bit order
+const TABLE: [(&str, &str, &str); 16] = [
+    ("bold", ansi::BOLD, "1"),
+    ("dim", ansi::DIM, "2"),
+    ("italic", ansi::ITALIC, "3"),
+    ("underline", ansi::UNDERLINE, "4"),
+    ("double_underline", ansi::DOUBLE_UNDERLINE, "21"),
+    ("curly_underline", ansi::CURLY_UNDERLINE, "4:3"),
+    ("dotted_underline", ansi::DOTTED_UNDERLINE, "4:4"),
+    ("dashed_underline", ansi::DASHED_UNDERLINE, "4:5"),
+    ("blink", ansi::BLINK, "5"),
+    ("rapid_blink", ansi::RAPID_BLINK, "6"),
+    ("reverse", ansi::REVERSE, "7"),
+    ("hidden", ansi::HIDDEN, "8"),
+    ("strikethrough", ansi::STRIKETHROUGH, "9"),
+    ("overline", ansi::OVERLINE, "53"),
+    ("framed", ansi::FRAMED, "51"),
+    ("encircled", ansi::ENCIRCLED, "52"),
+];
+
+impl Attributes {
+    pub const BOLD: Attributes = Attributes(1 << 0);
+    pub const DIM: Attributes = Attributes(1 << 1);
+    pub const ITALIC: Attributes = Attributes(1 << 2);
+    pub const UNDERLINE: Attributes = Attributes(1 << 3);
+    pub const DOUBLE_UNDERLINE: Attributes = Attributes(1 << 4);
+    pub const CURLY_UNDERLINE: Attributes = Attributes(1 << 5);
+    pub const DOTTED_UNDERLINE: Attributes = Attributes(1 << 6);
+    pub const DASHED_UNDERLINE: Attributes = Attributes(1 << 7);
+    pub const BLINK: Attributes = Attributes(1 << 8);
+    pub const RAPID_BLINK: Attributes = Attributes(1 << 9);
+    pub const REVERSE: Attributes = Attributes(1 << 10);
+    pub const HIDDEN: Attributes = Attributes(1 << 11);
+    pub const STRIKETHROUGH: Attributes = Attributes(1 << 12);
+    pub const OVERLINE: Attributes = Attributes(1 << 13);
+    pub const FRAMED: Attributes = Attributes(1 << 14);
+    pub const ENCIRCLED: Attributes = Attributes(1 << 15);
+
+    /// The empty set
+    pub const fn empty() -> Self {
+        Attributes(0)
+    }
+
+    /// Every known attribute
+    pub const fn all() -> Self {
+        Attributes((1 << TABLE.len()) - 1)
+    }
+
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Build a set from raw bits, dropping unknown ones
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Attributes(bits & Self::all().0)
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every attribute of `other` is in this set
+    pub const fn contains(&self, other: Attributes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether this set shares any attribute with `other`
+    pub const fn intersects(&self, other: Attributes) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    pub const fn union(self, other: Attributes) -> Self {
+        Attributes(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Attributes) -> Self {
+        Attributes(self.0 & other.0)
+    }
+
+    pub const fn difference(self, other: Attributes) -> Self {
+        Attributes(self.0 & !other.0)
+    }
+
+    pub fn insert(&mut self, other: Attributes) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: Attributes) {
+        self.0 &= !other.0;
+    }
+
+    pub fn toggle(&mut self, other: Attributes) {
+        self.0 ^= other.0;
+    }
+
+    /// Look up a single attribute by name
+    ///
+    /// Names are case-insensitive and `_`/`-` are optional, so
+    /// `"double_underline"`, `"double-underline"` and `"DoubleUnderline"`
+    /// are all accepted. `"strike"` is an alias for `"strikethrough"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized: String = name
+            .trim()
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+        let normalized = if normalized == "strike" { "strikethrough" } else { normalized.as_str() };
+
+        TABLE
+            .iter()
+            .position(|(name, _, _)| name.replace('_', "") == normalized)
+            .map(|bit| Attributes(1 << bit))
+    }
+
+    /// Look up a single attribute by its SGR parameter (`"1"`, `"4:3"`, ...)
+    pub fn from_sgr_param(param: &str) -> Option<Self> {
+        TABLE
+            .iter()
+            .position(|(_, _, sgr)| *sgr == param)
+            .map(|bit| Attributes(1 << bit))
+    }
+
+    /// Iterate over the single attributes in this set
+    pub fn iter(&self) -> impl Iterator<Item = Attributes> {
+        let bits = self.0;
+        (0..TABLE.len())
+            .filter(move |bit| bits & (1 << bit) != 0)
+            .map(|bit| Attributes(1 << bit))
+    }
+
+    /// Canonical names of the attributes in this set
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        self.entries().map(|(name, _, _)| name)
+    }
+
+    /// SGR parameters of the attributes in this set (`"1"`, `"4:3"`, ...)
+    pub fn sgr_params(&self) -> impl Iterator<Item = &'static str> {
+        self.entries().map(|(_, _, param)| param)
+    }
+
+    /// ANSI escape code of each attribute in this set
+    pub fn codes(&self) -> impl Iterator<Item = &'static str> {
+        self.entries().map(|(_, code, _)| code)
+    }
+
+    /// ANSI escape codes that turn on every attribute in this set
+    pub fn to_ansi(&self) -> String {
+        self.codes().collect()
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&'static str, &'static str, &'static str)> {
+        let bits = self.0;
+        TABLE
+            .iter()
+            .enumerate()
+            .filter(move |(bit, _)| bits & (1 << bit) != 0)
+            .map(|(_, entry)| *entry)
+    }
+}
+
+impl FromStr for Attributes {
+    type Err = MakeColorsError;
+
+    /// Parse a list of attribute names separated by spaces, commas or `|`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(|c: char| c.is_whitespace() || c == ',' || c == '|')
+            .filter(|name| !name.is_empty())
+            .try_fold(Attributes::empty(), |attrs, name| {
+                Attributes::from_name(name)
+                    .map(|attr| attrs | attr)
+                    .ok_or_else(|| MakeColorsError::InvalidAttribute(name.to_string()))
+            })
+    }
+}
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Attributes(")?;
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            f.write_str(&name.to_uppercase())?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+impl BitAnd for Attributes {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for Attributes {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Sub for Attributes {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for Attributes {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.remove(rhs);
+    }
+}
+
+impl Not for Attributes {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Attributes::all().difference(self)
+    }
+}
+
+impl FromIterator<Attributes> for Attributes {
+    fn from_iter<I: IntoIterator<Item = Attributes>>(iter: I) -> Self {
+        iter.into_iter().fold(Attributes::empty(), |acc, attr| acc | attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let mut attrs = Attributes::BOLD | Attributes::ITALIC;
+        attrs |= Attributes::BOLD;
+        assert_eq!(attrs.iter().count(), 2);
+        assert!(attrs.intersects(Attributes::ITALIC | Attributes::BLINK));
+
+        attrs -= Attributes::BOLD;
+        assert_eq!(attrs, Attributes::ITALIC);
+        assert_eq!(!Attributes::all(), Attributes::empty());
+        assert_eq!(Attributes::from_bits_truncate(u32::MAX), Attributes::all());
+    }
+
+    #[test]
+    fn test_parse_names() {
+        for (name, _, _) in TABLE {
+            let attr = Attributes::from_name(name).unwrap();
+            assert_eq!(attr.to_string(), name);
+        }
+        assert_eq!(Attributes::from_name("Rapid-Blink"), Some(Attributes::RAPID_BLINK));
+        assert_eq!(Attributes::from_name("strike"), Some(Attributes::STRIKETHROUGH));
+        assert!(matches!("bold blod".parse::<Attributes>(), Err(MakeColorsError::InvalidAttribute(_))));
+        assert_eq!("".parse::<Attributes>().unwrap(), Attributes::empty());
+    }
+
+    #[test]
+    fn test_sgr_encoding() {
+        let attrs = Attributes::OVERLINE | Attributes::BOLD | Attributes::DOUBLE_UNDERLINE;
+        assert_eq!(attrs.sgr_params().collect::<Vec<_>>(), ["1", "21", "53"]);
+        assert_eq!(Attributes::from_sgr_param("4:3"), Some(Attributes::CURLY_UNDERLINE));
+        assert_eq!(Attributes::from_sgr_param("31"), None);
+        assert_eq!(attrs.to_ansi(), format!("{}{}{}", ansi::BOLD, ansi::DOUBLE_UNDERLINE, ansi::OVERLINE));
+        assert_eq!(format!("{:?}", attrs), "Attributes(BOLD | DOUBLE_UNDERLINE | OVERLINE)");
+    }
+}
@@ -0,0 +1,511 @@
+// File: src\color.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Typed color values for make_colors.
+// License: MIT
+
+//! Typed colors.
+//!
+//! [`Color`] is the typed counterpart of the color strings accepted by
+//! [`make_colors`](crate::make_colors). Parsing a misspelled name returns
+//! [`MakeColorsError::InvalidColorName`] instead of silently producing
+//! uncolored text.
+//!
+//! # Examples
+//! ```
+//! use make_colors::Color;
+//!
+//! let red: Color = "r".parse().unwrap();
+//! assert_eq!(red, Color::Red);
+//! assert_eq!("lightblue".parse::<Color>().unwrap(), Color::BrightBlue);
+//! assert_eq!("#FF8800".parse::<Color>().unwrap(), Color::Rgb(255, 136, 0));
+//! assert!("purpel".parse::<Color>().is_err());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::colorspace::{Hsl, Hsv};
+use crate::quantize::{ansi256_to_rgb, ANSI16_PALETTE};
+use crate::{ansi, names, MakeColorsError};
+
+/// A terminal color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// Index into the xterm 256-color palette
+    Ansi256(u8),
+    /// 24-bit true color
+    Rgb(u8, u8, u8),
+    /// The terminal's default color
+    #[default]
+    Default,
+}
+
+impl Color {
+    /// ANSI escape code that sets this color as the foreground
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::{ansi, Color};
+    ///
+    /// assert_eq!(Color::Red.fg_code(), ansi::RED);
+    /// assert_eq!(Color::Rgb(0, 255, 255).fg_code(), "\x1b[38;2;0;255;255m");
+    /// ```
+    pub fn fg_code(&self) -> String {
+        self.code(false)
+    }
+
+    /// ANSI escape code that sets this color as the background
+    pub fn bg_code(&self) -> String {
+        self.code(true)
+    }
+
+    /// RGB value of this color, using the xterm palette for named and
+    /// 256-color entries; `None` for [`Color::Default`]
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::Color;
+    ///
+    /// assert_eq!(Color::BrightRed.to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Ansi256(208).to_rgb(), Some((255, 135, 0)));
+    /// assert_eq!(Color::Default.to_rgb(), None);
+    /// ```
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        match *self {
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::Ansi256(n) => Some(ansi256_to_rgb(n)),
+            Color::Default => None,
+            named => Some(ANSI16_PALETTE[named.ansi16_index()? as usize]),
+        }
+    }
+
+    /// Index of a standard color in the 256-color palette (`Black` = 0 ... `BrightWhite` = 15)
+    pub fn ansi16_index(&self) -> Option<u8> {
+        let index = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+            _ => return None,
+        };
+        Some(index)
+    }
+
+    fn code(&self, background: bool) -> String {
+        let mut code = String::new();
+        let _ = self.write_code(&mut code, background);
+        code
+    }
+
+    /// SGR parameters selecting this color, e.g. `31`, `48;5;208` or `39`
+    pub(crate) fn sgr_params(&self, background: bool) -> String {
+        let layer: u16 = if background { 48 } else { 38 };
+        match *self {
+            Color::Default => (layer + 1).to_string(),
+            Color::Ansi256(n) => format!("{};5;{}", layer, n),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", layer, r, g, b),
+            named => {
+                let index = named.ansi16_index().unwrap_or(0) as u16;
+                let code = if index < 8 { 30 + index } else { 90 + index - 8 };
+                (code + if background { 10 } else { 0 }).to_string()
+            }
+        }
+    }
+
+    /// Write the escape code for this color without allocating
+    pub(crate) fn write_code<W: fmt::Write>(&self, w: &mut W, background: bool) -> fmt::Result {
+        let layer = if background { 48 } else { 38 };
+        let code = match (self, background) {
+            (Color::Black, false) => ansi::BLACK,
+            (Color::Red, false) => ansi::RED,
+            (Color::Green, false) => ansi::GREEN,
+            (Color::Yellow, false) => ansi::YELLOW,
+            (Color::Blue, false) => ansi::BLUE,
+            (Color::Magenta, false) => ansi::MAGENTA,
+            (Color::Cyan, false) => ansi::CYAN,
+            (Color::White, false) => ansi::WHITE,
+
+            (Color::BrightBlack, false) => ansi::LIGHT_BLACK,
+            (Color::BrightRed, false) => ansi::LIGHT_RED,
+            (Color::BrightGreen, false) => ansi::LIGHT_GREEN,
+            (Color::BrightYellow, false) => ansi::LIGHT_YELLOW,
+            (Color::BrightBlue, false) => ansi::LIGHT_BLUE,
+            (Color::BrightMagenta, false) => ansi::LIGHT_MAGENTA,
+            (Color::BrightCyan, false) => ansi::LIGHT_CYAN,
+            (Color::BrightWhite, false) => ansi::LIGHT_WHITE,
+
+            (Color::Black, true) => ansi::BG_BLACK,
+            (Color::Red, true) => ansi::BG_RED,
+            (Color::Green, true) => ansi::BG_GREEN,
+            (Color::Yellow, true) => ansi::BG_YELLOW,
+            (Color::Blue, true) => ansi::BG_BLUE,
+            (Color::Magenta, true) => ansi::BG_MAGENTA,
+            (Color::Cyan, true) => ansi::BG_CYAN,
+            (Color::White, true) => ansi::BG_WHITE,
+
+            (Color::BrightBlack, true) => ansi::BG_LIGHT_BLACK,
+            (Color::BrightRed, true) => ansi::BG_LIGHT_RED,
+            (Color::BrightGreen, true) => ansi::BG_LIGHT_GREEN,
+            (Color::BrightYellow, true) => ansi::BG_LIGHT_YELLOW,
+            (Color::BrightBlue, true) => ansi::BG_LIGHT_BLUE,
+            (Color::BrightMagenta, true) => ansi::BG_LIGHT_MAGENTA,
+            (Color::BrightCyan, true) => ansi::BG_LIGHT_CYAN,
+            (Color::BrightWhite, true) => ansi::BG_LIGHT_WHITE,
+
+            (Color::Default, false) => ansi::DEFAULT,
+            (Color::Default, true) => ansi::BG_DEFAULT,
+
+            (Color::Ansi256(n), _) => return write!(w, "\x1b[{};5;{}m", layer, n),
+            (Color::Rgb(r, g, b), _) => return write!(w, "\x1b[{};2;{};{};{}m", layer, r, g, b),
+        };
+
+        w.write_str(code)
+    }
+}
+
+impl FromStr for Color {
+    type Err = MakeColorsError;
+
+    /// Parse a color string, see [`parse_color`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s)
+    }
+}
+
+/// Parse a color string
+///
+/// Accepted forms, case-insensitive:
+///
+/// - ANSI names and abbreviations: `"red"`, `"r"`, `"lightblue"`, `"default"`
+/// - CSS/X11 names from the [`names`] module: `"coral"`, `"rebeccapurple"`;
+///   a `css:` prefix skips the ANSI names, so `"css:lightblue"` is the CSS
+///   color rather than the terminal's light blue
+/// - hex with 3, 4, 6 or 8 digits: `"#0ff"`, `"#0ff8"`, `"#00FFFF"`,
+///   `"#00FFFF80"`; 6 and 8 digits also work without `#`
+/// - `rgb(255, 0, 0)` or `rgb(100%, 0%, 0%)`
+/// - `hsl(120, 50%, 50%)` and `hsv(120, 100%, 80%)`, hue in degrees
+/// - `color(n)` or `ansi(n)` for the 256-color palette
+///
+/// Functions also accept space-separated arguments, an `a` suffix
+/// (`rgba`, `hsla`, `hsva`) and a trailing alpha value, written after a
+/// comma or `/`. Terminals cannot blend, so alpha is validated and then
+/// dropped, as are the alpha digits of 4 and 8-digit hex colors.
+///
+/// # Examples
+/// ```
+/// use make_colors::{parse_color, Color, MakeColorsError};
+///
+/// assert_eq!(parse_color("#0ff").unwrap(), Color::Rgb(0, 255, 255));
+/// assert_eq!(parse_color("rgb(100%, 50%, 0%)").unwrap(), Color::Rgb(255, 128, 0));
+/// assert_eq!(parse_color("hsl(120, 100%, 25%)").unwrap(), Color::Rgb(0, 128, 0));
+/// assert_eq!(parse_color("hsva(0 0% 100% / 0.5)").unwrap(), Color::Rgb(255, 255, 255));
+///
+/// assert!(matches!(parse_color("#12345"), Err(MakeColorsError::InvalidHexLength(_))));
+/// assert!(matches!(
+///     parse_color("hsl(120, 150%, 50%)"),
+///     Err(MakeColorsError::InvalidColorComponent { component: "saturation", .. })
+/// ));
+/// ```
+pub fn parse_color(s: &str) -> Result<Color, MakeColorsError> {
+    let color = s.trim().to_lowercase();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        let (r, g, b) = parse_hex(hex, s)?;
+        return Ok(Color::Rgb(r, g, b));
+    }
+
+    if let Some(open) = color.find('(') {
+        let args = color[open + 1..]
+            .strip_suffix(')')
+            .ok_or_else(|| MakeColorsError::InvalidColorFunction(s.to_string()))?;
+        return parse_function(color[..open].trim(), args, s);
+    }
+
+    if let Some(name) = color.strip_prefix("css:") {
+        let (r, g, b) = names::lookup(name).ok_or_else(|| MakeColorsError::InvalidColorName(s.to_string()))?;
+        return Ok(Color::Rgb(r, g, b));
+    }
+    if color == "default" {
+        return Ok(Color::Default);
+    }
+    if let Some(named) = ansi_name(&color) {
+        return Ok(named);
+    }
+    if let Some((r, g, b)) = names::lookup(&color) {
+        return Ok(Color::Rgb(r, g, b));
+    }
+    if matches!(color.len(), 6 | 8) && color.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let (r, g, b) = parse_hex(&color, s)?;
+        return Ok(Color::Rgb(r, g, b));
+    }
+
+    Err(MakeColorsError::InvalidColorName(s.to_string()))
+}
+
+/// ANSI color names and abbreviations, with an optional `light` prefix
+fn ansi_name(color: &str) -> Option<Color> {
+    let (base_color, is_light) = match color.strip_prefix("light") {
+        Some(base) => (base, true),
+        None => (color, false),
+    };
+
+    // Map abbreviations and full names
+    let parsed = match (base_color, is_light) {
+        ("b" | "bk" | "black", false) => Color::Black,
+        ("r" | "rd" | "re" | "red", false) => Color::Red,
+        ("g" | "gr" | "ge" | "green", false) => Color::Green,
+        ("y" | "ye" | "yl" | "yellow", false) => Color::Yellow,
+        ("bl" | "blue", false) => Color::Blue,
+        ("m" | "mg" | "ma" | "magenta", false) => Color::Magenta,
+        ("c" | "cy" | "cn" | "cyan", false) => Color::Cyan,
+        ("w" | "wh" | "wi" | "wt" | "white", false) => Color::White,
+
+        ("b" | "bk" | "black", true) => Color::BrightBlack,
+        ("r" | "rd" | "re" | "red", true) => Color::BrightRed,
+        ("g" | "gr" | "ge" | "green", true) => Color::BrightGreen,
+        ("y" | "ye" | "yl" | "yellow", true) => Color::BrightYellow,
+        ("bl" | "blue", true) => Color::BrightBlue,
+        ("m" | "mg" | "ma" | "magenta", true) => Color::BrightMagenta,
+        ("c" | "cy" | "cn" | "cyan", true) => Color::BrightCyan,
+        ("w" | "wh" | "wi" | "wt" | "white", true) => Color::BrightWhite,
+
+        _ => return None,
+    };
+
+    Some(parsed)
+}
+
+/// Parse 3, 4, 6 or 8 hex digits (without `#`), dropping alpha
+pub(crate) fn parse_hex(hex: &str, input: &str) -> Result<(u8, u8, u8), MakeColorsError> {
+    if let Some(digit) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(MakeColorsError::InvalidHexDigit {
+            input: input.to_string(),
+            digit,
+        });
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap_or(0);
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+    match hex.len() {
+        3 | 4 => Ok((digit(0) * 17, digit(1) * 17, digit(2) * 17)),
+        6 | 8 => Ok((pair(0), pair(2), pair(4))),
+        _ => Err(MakeColorsError::InvalidHexLength(input.to_string())),
+    }
+}
+
+/// Parse the arguments of `rgb(...)`, `hsl(...)`, `hsv(...)` or `color(...)`
+fn parse_function(name: &str, args: &str, input: &str) -> Result<Color, MakeColorsError> {
+    // Commas and `/` separate arguments, so an empty piece between them is a
+    // missing argument; runs of whitespace are a separator of their own
+    let pieces: Vec<Vec<&str>> = if args.trim().is_empty() {
+        Vec::new()
+    } else {
+        args.split([',', '/']).map(|piece| piece.split_whitespace().collect()).collect()
+    };
+    let missing = pieces.iter().any(|piece| piece.is_empty());
+    let args: Vec<&str> = pieces.iter().flatten().copied().collect();
+    let function = name.strip_suffix('a').unwrap_or(name);
+
+    let (components, expected): (&[&'static str], usize) = match function {
+        "rgb" => (&["red", "green", "blue"], 3),
+        "hsl" => (&["hue", "saturation", "lightness"], 3),
+        "hsv" | "hsb" => (&["hue", "saturation", "value"], 3),
+        "color" | "ansi" if name == function => (&["index"], 1),
+        _ => return Err(MakeColorsError::InvalidColorFunction(input.to_string())),
+    };
+    if missing || (args.len() != expected && !(expected == 3 && args.len() == 4)) {
+        return Err(MakeColorsError::InvalidColorArguments {
+            function: name.to_string(),
+            expected,
+            found: args.len() + pieces.iter().filter(|piece| piece.is_empty()).count(),
+        });
+    }
+
+    let invalid = |index: usize| MakeColorsError::InvalidColorComponent {
+        function: name.to_string(),
+        component: if index < components.len() { components[index] } else { "alpha" },
+        value: args[index].to_string(),
+    };
+    if args.len() == 4 {
+        unit_or_percent(args[3], 1.0).ok_or_else(|| invalid(3))?;
+    }
+
+    match function {
+        "rgb" => {
+            let channel = |i: usize| unit_or_percent(args[i], 255.0).map(|c| c.round() as u8).ok_or_else(|| invalid(i));
+            Ok(Color::Rgb(channel(0)?, channel(1)?, channel(2)?))
+        }
+        "color" | "ansi" => args[0].parse().map(Color::Ansi256).map_err(|_| invalid(0)),
+        _ => {
+            let hue = hue(args[0]).ok_or_else(|| invalid(0))?;
+            let percent = |i: usize| percentage(args[i]).ok_or_else(|| invalid(i));
+            let (saturation, third) = (percent(1)?, percent(2)?);
+            Ok(if function == "hsl" {
+                Hsl { h: hue, s: saturation, l: third }.into()
+            } else {
+                Hsv { h: hue, s: saturation, v: third }.into()
+            })
+        }
+    }
+}
+
+/// A number in `0..=max`, or a percentage of `max`
+fn unit_or_percent(value: &str, max: f32) -> Option<f32> {
+    let number = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0 * max,
+        None => value.parse::<f32>().ok()?,
+    };
+    (number.is_finite() && (0.0..=max).contains(&number)).then_some(number)
+}
+
+/// A percentage in `0..=100`, with or without `%`, as a fraction
+fn percentage(value: &str) -> Option<f32> {
+    let number = value.strip_suffix('%').unwrap_or(value).parse::<f32>().ok()?;
+    (number.is_finite() && (0.0..=100.0).contains(&number)).then_some(number / 100.0)
+}
+
+/// An angle in degrees, with an optional `deg` suffix, wrapped to `0..360`
+fn hue(value: &str) -> Option<f32> {
+    let number = value.strip_suffix("deg").unwrap_or(value).parse::<f32>().ok()?;
+    number.is_finite().then_some(number.rem_euclid(360.0))
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Color::Black => "black",
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+            Color::White => "white",
+            Color::BrightBlack => "lightblack",
+            Color::BrightRed => "lightred",
+            Color::BrightGreen => "lightgreen",
+            Color::BrightYellow => "lightyellow",
+            Color::BrightBlue => "lightblue",
+            Color::BrightMagenta => "lightmagenta",
+            Color::BrightCyan => "lightcyan",
+            Color::BrightWhite => "lightwhite",
+            Color::Default => "default",
+            Color::Ansi256(n) => return write!(f, "ansi({})", n),
+            Color::Rgb(r, g, b) => return write!(f, "#{:02X}{:02X}{:02X}", r, g, b),
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_names_and_abbreviations() {
+        assert_eq!("red".parse::<Color>().unwrap(), Color::Red);
+        assert_eq!(" BL ".parse::<Color>().unwrap(), Color::Blue);
+        assert_eq!("lightgreen".parse::<Color>().unwrap(), Color::BrightGreen);
+        assert_eq!("rgb(1, 2, 3)".parse::<Color>().unwrap(), Color::Rgb(1, 2, 3));
+        assert_eq!("color(123)".parse::<Color>().unwrap(), Color::Ansi256(123));
+        assert_eq!("ANSI( 7 )".parse::<Color>().unwrap(), Color::Ansi256(7));
+        assert!("color(256)".parse::<Color>().is_err());
+        assert_eq!("lightcoral".parse::<Color>().unwrap(), Color::Rgb(240, 128, 128));
+        assert_eq!("RebeccaPurple".parse::<Color>().unwrap(), Color::Rgb(102, 51, 153));
+        assert_eq!("css:lightgreen".parse::<Color>().unwrap(), Color::Rgb(144, 238, 144));
+        assert_eq!("CSS: Light Yellow".parse::<Color>().unwrap(), Color::Rgb(255, 255, 224));
+        assert_eq!("css:green".parse::<Color>().unwrap(), Color::Rgb(0, 128, 0));
+        assert!(matches!("css:r".parse::<Color>(), Err(MakeColorsError::InvalidColorName(_))));
+        assert!(matches!("redd".parse::<Color>(), Err(MakeColorsError::InvalidColorName(_))));
+        assert!(matches!("#12345".parse::<Color>(), Err(MakeColorsError::InvalidHexLength(_))));
+    }
+
+    #[test]
+    fn test_parse_extended_syntax() {
+        assert_eq!(parse_color("#F80").unwrap(), Color::Rgb(255, 136, 0));
+        assert_eq!(parse_color("#FF880080").unwrap(), Color::Rgb(255, 136, 0));
+        assert_eq!(parse_color("ff8800").unwrap(), Color::Rgb(255, 136, 0));
+        assert_eq!(parse_color("rgba(255 136 0 / 50%)").unwrap(), Color::Rgb(255, 136, 0));
+        assert_eq!(parse_color("hsl(-120deg, 100%, 50%)").unwrap(), Color::Rgb(0, 0, 255));
+        assert_eq!(parse_color("hsl(0, 0%, 50%)").unwrap(), Color::Rgb(128, 128, 128));
+        assert_eq!(parse_color("hsv(60, 100, 100)").unwrap(), Color::Rgb(255, 255, 0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse_color("#12g"), Err(MakeColorsError::InvalidHexDigit { digit: 'g', .. })));
+        assert!(matches!(parse_color("#12345"), Err(MakeColorsError::InvalidHexLength(_))));
+        assert!(matches!(parse_color("cmyk(0, 0, 0, 0)"), Err(MakeColorsError::InvalidColorFunction(_))));
+        assert!(matches!(parse_color("rgb(1, 2, 3"), Err(MakeColorsError::InvalidColorFunction(_))));
+        assert!(matches!(
+            parse_color("rgb(1, 2)"),
+            Err(MakeColorsError::InvalidColorArguments { expected: 3, found: 2, .. })
+        ));
+        assert!(matches!(
+            parse_color("rgb(1,,2,3)"),
+            Err(MakeColorsError::InvalidColorArguments { expected: 3, found: 4, .. })
+        ));
+        assert!(matches!(
+            parse_color("rgb(1,2,3,)"),
+            Err(MakeColorsError::InvalidColorArguments { expected: 3, found: 4, .. })
+        ));
+        assert!(matches!(parse_color("rgb(1 2 3 / )"), Err(MakeColorsError::InvalidColorArguments { .. })));
+        assert_eq!(parse_color("rgb( 1 ,2,  3 )").unwrap(), Color::Rgb(1, 2, 3));
+        assert_eq!(parse_color("rgb(1 2 3 / 50%)").unwrap(), Color::Rgb(1, 2, 3));
+        match parse_color("rgb(1, 256, 3)") {
+            Err(MakeColorsError::InvalidColorComponent { function, component, value }) => {
+                assert_eq!((function.as_str(), component, value.as_str()), ("rgb", "green", "256"));
+            }
+            other => panic!("expected a component error, got {:?}", other),
+        }
+        assert!(matches!(
+            parse_color("hsla(10, 50%, 50%, 2)"),
+            Err(MakeColorsError::InvalidColorComponent { component: "alpha", .. })
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let colors = [Color::Black, Color::BrightCyan, Color::Ansi256(123), Color::Rgb(255, 0, 16), Color::Default];
+        for color in colors {
+            assert_eq!(color.to_string().parse::<Color>().unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(Color::BrightRed.fg_code(), ansi::LIGHT_RED);
+        assert_eq!(Color::Blue.bg_code(), ansi::BG_BLUE);
+        assert_eq!(Color::Ansi256(208).bg_code(), "\x1b[48;5;208m");
+        assert_eq!(Color::Default.fg_code(), "\x1b[39m");
+    }
+}
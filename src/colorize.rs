@@ -0,0 +1,185 @@
+// File: src\colorize.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Extension trait for colorizing strings and Display values.
+// License: MIT
+
+//! Colorizing extension methods.
+//!
+//! [`Colorize`] is implemented for every [`Display`](std::fmt::Display) type,
+//! including `&str` and `String`. Each method returns a lazily rendered
+//! [`Styled`] value, and the same methods on [`Styled`] update its style in
+//! place, so calls can be chained freely.
+//!
+//! # Examples
+//! ```
+//! use make_colors::Colorize;
+//!
+//! println!("{}", "Error".red().bold());
+//! println!("{}", "Notice".white().on_blue());
+//! println!("{}", format!("{} files", 3).bright_green().underline());
+//! println!("{}", "Orange".hex("#FF8800").italic());
+//! println!("{}", 42u32.rgb(0, 128, 128));
+//! ```
+
+use std::fmt;
+
+use crate::{Attributes, Color, Style, Styled};
+
+macro_rules! colorize_methods {
+    ($($(#[$meta:meta])* $name:ident($($arg:ident: $ty:ty),*) => |$s:ident| $apply:expr;)*) => {
+        /// Colorizing methods for any displayable value
+        pub trait Colorize: Sized {
+            /// Layer `style` on top of the value's style
+            fn style(self, style: Style) -> Styled<Self> {
+                Styled::new(self, style)
+            }
+
+            $(
+                $(#[$meta])*
+                fn $name(self $(, $arg: $ty)*) -> Styled<Self> {
+                    let $s = Style::new();
+                    Styled::new(self, $apply)
+                }
+            )*
+        }
+
+        /// Chaining counterparts of the [`Colorize`] methods
+        impl<T> Styled<T> {
+            /// Layer `style` on top of the current style
+            pub fn style(mut self, style: Style) -> Self {
+                self.style = self.style.patch(style);
+                self
+            }
+
+            $(
+                $(#[$meta])*
+                pub fn $name(mut self $(, $arg: $ty)*) -> Self {
+                    let $s = self.style;
+                    self.style = $apply;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+colorize_methods! {
+    /// Set the foreground color
+    fg(color: Color) => |s| s.fg(color);
+    /// Set the background color
+    bg(color: Color) => |s| s.bg(color);
+    /// Set the foreground by name, hex or `rgb(...)`; unknown colors are ignored
+    color(color: &str) => |s| match color.parse() {
+        Ok(color) => s.fg(color),
+        Err(_) => s,
+    };
+    /// Set the background by name, hex or `rgb(...)`; unknown colors are ignored
+    on_color(color: &str) => |s| match color.parse() {
+        Ok(color) => s.bg(color),
+        Err(_) => s,
+    };
+    /// Set the foreground from a hex color; invalid hex is ignored
+    hex(hex: &str) => |s| match crate::hex_to_rgb(hex) {
+        Ok((r, g, b)) => s.fg(Color::Rgb(r, g, b)),
+        Err(_) => s,
+    };
+    /// Set the background from a hex color; invalid hex is ignored
+    on_hex(hex: &str) => |s| match crate::hex_to_rgb(hex) {
+        Ok((r, g, b)) => s.bg(Color::Rgb(r, g, b)),
+        Err(_) => s,
+    };
+    rgb(r: u8, g: u8, b: u8) => |s| s.fg(Color::Rgb(r, g, b));
+    on_rgb(r: u8, g: u8, b: u8) => |s| s.bg(Color::Rgb(r, g, b));
+    /// Set the foreground from the 256-color palette
+    ansi(index: u8) => |s| s.fg(Color::Ansi256(index));
+    /// Set the background from the 256-color palette
+    on_ansi(index: u8) => |s| s.bg(Color::Ansi256(index));
+
+    black() => |s| s.fg(Color::Black);
+    red() => |s| s.fg(Color::Red);
+    green() => |s| s.fg(Color::Green);
+    yellow() => |s| s.fg(Color::Yellow);
+    blue() => |s| s.fg(Color::Blue);
+    magenta() => |s| s.fg(Color::Magenta);
+    cyan() => |s| s.fg(Color::Cyan);
+    white() => |s| s.fg(Color::White);
+    bright_black() => |s| s.fg(Color::BrightBlack);
+    bright_red() => |s| s.fg(Color::BrightRed);
+    bright_green() => |s| s.fg(Color::BrightGreen);
+    bright_yellow() => |s| s.fg(Color::BrightYellow);
+    bright_blue() => |s| s.fg(Color::BrightBlue);
+    bright_magenta() => |s| s.fg(Color::BrightMagenta);
+    bright_cyan() => |s| s.fg(Color::BrightCyan);
+    bright_white() => |s| s.fg(Color::BrightWhite);
+
+    on_black() => |s| s.bg(Color::Black);
+    on_red() => |s| s.bg(Color::Red);
+    on_green() => |s| s.bg(Color::Green);
+    on_yellow() => |s| s.bg(Color::Yellow);
+    on_blue() => |s| s.bg(Color::Blue);
+    on_magenta() => |s| s.bg(Color::Magenta);
+    on_cyan() => |s| s.bg(Color::Cyan);
+    on_white() => |s| s.bg(Color::White);
+    on_bright_black() => |s| s.bg(Color::BrightBlack);
+    on_bright_red() => |s| s.bg(Color::BrightRed);
+    on_bright_green() => |s| s.bg(Color::BrightGreen);
+    on_bright_yellow() => |s| s.bg(Color::BrightYellow);
+    on_bright_blue() => |s| s.bg(Color::BrightBlue);
+    on_bright_magenta() => |s| s.bg(Color::BrightMagenta);
+    on_bright_cyan() => |s| s.bg(Color::BrightCyan);
+    on_bright_white() => |s| s.bg(Color::BrightWhite);
+
+    /// Add a set of attributes
+    attrs(attrs: Attributes) => |s| s.attrs(attrs);
+    bold() => |s| s.attrs(Attributes::BOLD);
+    dim() => |s| s.attrs(Attributes::DIM);
+    italic() => |s| s.attrs(Attributes::ITALIC);
+    underline() => |s| s.attrs(Attributes::UNDERLINE);
+    double_underline() => |s| s.attrs(Attributes::DOUBLE_UNDERLINE);
+    curly_underline() => |s| s.attrs(Attributes::CURLY_UNDERLINE);
+    blink() => |s| s.attrs(Attributes::BLINK);
+    reverse() => |s| s.attrs(Attributes::REVERSE);
+    hidden() => |s| s.attrs(Attributes::HIDDEN);
+    strikethrough() => |s| s.attrs(Attributes::STRIKETHROUGH);
+    overline() => |s| s.attrs(Attributes::OVERLINE);
+}
+
+impl<T: fmt::Display> Colorize for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support;
+
+    #[test]
+    fn test_chaining_updates_one_style() {
+        let styled = "text".red().on_blue().bold().underline();
+        assert_eq!(styled.value, "text");
+        assert_eq!(
+            styled.style,
+            Style::new()
+                .fg(Color::Red)
+                .bg(Color::Blue)
+                .attrs(Attributes::BOLD | Attributes::UNDERLINE)
+        );
+        assert_eq!("x".bold().green().style, "x".green().bold().style);
+    }
+
+    #[test]
+    fn test_color_sources() {
+        assert_eq!("x".hex("#FF8800").style.fg, Some(Color::Rgb(255, 136, 0)));
+        assert_eq!("x".hex("nope").style.fg, None);
+        assert_eq!(String::from("x").color("lightred").style.fg, Some(Color::BrightRed));
+        assert_eq!(7u8.on_ansi(236).style.bg, Some(Color::Ansi256(236)));
+        let base = Style::new().fg(Color::Cyan);
+        assert_eq!("x".style(base).italic().style, base.attrs(Attributes::ITALIC));
+    }
+
+    #[test]
+    fn test_rendering() {
+        support::force_true_color();
+        assert_eq!(format!("{}", "hi".red().bold()), "\x1b[1m\x1b[31mhi\x1b[0m");
+        assert_eq!(format!("{:<4}|", 12u8.green()), "\x1b[32m12  \x1b[0m|");
+    }
+}
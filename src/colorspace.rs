@@ -0,0 +1,558 @@
+// File: src\colorspace.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Color space conversions (HSL, HSV, CIE Lab, OKLab, OKLCH) and color manipulation.
+// License: MIT
+
+//! Color spaces and color math.
+//!
+//! [`Rgb`] holds sRGB channels as floats in `0.0..=1.0`; [`Hsl`], [`Hsv`],
+//! [`Lab`] (CIE L\*a\*b\*, D65), [`Oklab`] and [`Oklch`] convert to and from
+//! it with `From`. Conversions work on unclamped floats, so a round trip
+//! through any space gives back the same color; clamping only happens when
+//! a value is turned into a [`Color`].
+//!
+//! [`Color`] gets the usual manipulations: [`lighten`](Color::lighten),
+//! [`darken`](Color::darken), [`saturate`](Color::saturate),
+//! [`desaturate`](Color::desaturate), [`rotate_hue`](Color::rotate_hue),
+//! [`mix`](Color::mix), [`invert`](Color::invert) and
+//! [`grayscale`](Color::grayscale). Named and 256-color values are resolved
+//! with the xterm palette and the result is always a [`Color::Rgb`];
+//! [`Color::Default`] has no RGB value and is returned unchanged.
+//!
+//! # Examples
+//! ```
+//! use make_colors::colorspace::{Hsl, Oklch};
+//! use make_colors::Color;
+//!
+//! let orange = Color::Rgb(255, 136, 0);
+//! let hsl: Hsl = orange.convert().unwrap();
+//! assert_eq!(hsl.h.round(), 32.0);
+//!
+//! assert_eq!(orange.darken(0.2), Color::Rgb(153, 82, 0));
+//! assert_eq!(Color::Black.mix(Color::BrightWhite, 0.5), Color::Rgb(128, 128, 128));
+//!
+//! // Heatmap steps with even perceived lightness
+//! let steps: Vec<Color> = (0..5).map(|i| Oklch { l: 0.7, c: 0.15, h: 140.0 - 35.0 * i as f32 }.into()).collect();
+//! assert_eq!(steps.len(), 5);
+//! ```
+
+use crate::Color;
+
+/// sRGB color with gamma-encoded channels in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+/// Hue in degrees (`0..360`), saturation and lightness in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+/// Hue in degrees (`0..360`), saturation and value in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+/// CIE L\*a\*b\* under the D65 white point, `l` in `0.0..=100.0`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// OKLab, `l` in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// Polar OKLab: lightness, chroma and hue in degrees
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+/// Space in which [`Color::mix_in`] interpolates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorSpace {
+    /// Gamma-encoded sRGB, as most tools mix
+    #[default]
+    Rgb,
+    /// HSL, taking the shorter way around the hue circle
+    Hsl,
+    /// OKLab, perceptually even and without hue shifts
+    Oklab,
+    /// OKLCH, keeping chroma while the hue turns
+    Oklch,
+}
+
+impl Rgb {
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Rgb { r, g, b }
+    }
+
+    pub fn from_u8(r: u8, g: u8, b: u8) -> Self {
+        Rgb::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+    }
+
+    /// 8-bit channels, rounded and clamped to the sRGB gamut
+    pub fn to_u8(self) -> (u8, u8, u8) {
+        let channel = |c: f32| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+        (channel(self.r), channel(self.g), channel(self.b))
+    }
+
+    /// Linear-light channels
+    pub fn to_linear(self) -> [f32; 3] {
+        let linear = |c: f32| {
+            if c.abs() <= 0.04045 {
+                c / 12.92
+            } else {
+                c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        [linear(self.r), linear(self.g), linear(self.b)]
+    }
+
+    /// Gamma-encode linear-light channels
+    pub fn from_linear([r, g, b]: [f32; 3]) -> Self {
+        let encode = |c: f32| {
+            if c.abs() <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                c.signum() * (1.055 * c.abs().powf(1.0 / 2.4) - 0.055)
+            }
+        };
+        Rgb::new(encode(r), encode(g), encode(b))
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Self {
+        let (h, max, min) = hue_max_min(rgb);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        let s = if delta == 0.0 { 0.0 } else { delta / (1.0 - (2.0 * l - 1.0).abs()) };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        hue_to_rgb(hsl.h, chroma, hsl.l - chroma / 2.0)
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Self {
+        let (h, max, min) = hue_max_min(rgb);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Self {
+        let chroma = hsv.v * hsv.s;
+        hue_to_rgb(hsv.h, chroma, hsv.v - chroma)
+    }
+}
+
+/// D65 reference white in XYZ
+const WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        let [r, g, b] = rgb.to_linear();
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+        let f = |t: f32| {
+            const DELTA: f32 = 6.0 / 29.0;
+            if t > DELTA.powi(3) {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / WHITE[0]), f(y / WHITE[1]), f(z / WHITE[2]));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl From<Lab> for Rgb {
+    fn from(lab: Lab) -> Self {
+        let finv = |t: f32| {
+            const DELTA: f32 = 6.0 / 29.0;
+            if t > DELTA {
+                t.powi(3)
+            } else {
+                3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+            }
+        };
+        let fy = (lab.l + 16.0) / 116.0;
+        let x = WHITE[0] * finv(fy + lab.a / 500.0);
+        let y = WHITE[1] * finv(fy);
+        let z = WHITE[2] * finv(fy - lab.b / 200.0);
+
+        Rgb::from_linear([
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+        ])
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        let [r, g, b] = rgb.to_linear();
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Rgb {
+    fn from(lab: Oklab) -> Self {
+        let l = (lab.l + 0.396_337_78 * lab.a + 0.215_803_76 * lab.b).powi(3);
+        let m = (lab.l - 0.105_561_346 * lab.a - 0.063_854_17 * lab.b).powi(3);
+        let s = (lab.l - 0.089_484_18 * lab.a - 1.291_485_5 * lab.b).powi(3);
+
+        Rgb::from_linear([
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ])
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        Oklch {
+            l: lab.l,
+            c: lab.a.hypot(lab.b),
+            h: lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let (sin, cos) = lch.h.to_radians().sin_cos();
+        Oklab {
+            l: lch.l,
+            a: lch.c * cos,
+            b: lch.c * sin,
+        }
+    }
+}
+
+impl From<Rgb> for Oklch {
+    fn from(rgb: Rgb) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklch> for Rgb {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+macro_rules! into_color {
+    ($($space:ty),*) => {
+        $(
+            impl From<$space> for Color {
+                fn from(value: $space) -> Self {
+                    let (r, g, b) = Rgb::from(value).to_u8();
+                    Color::Rgb(r, g, b)
+                }
+            }
+        )*
+    };
+}
+
+into_color!(Hsl, Hsv, Lab, Oklab, Oklch);
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb.to_u8();
+        Color::Rgb(r, g, b)
+    }
+}
+
+/// Hue in degrees plus the largest and smallest channel
+fn hue_max_min(Rgb { r, g, b }: Rgb) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (hue.rem_euclid(360.0), max, min)
+}
+
+fn hue_to_rgb(hue: f32, chroma: f32, offset: f32) -> Rgb {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Rgb::new(r + offset, g + offset, b + offset)
+}
+
+/// Interpolate between two hues along the shorter arc
+fn lerp_hue(from: f32, to: f32, t: f32) -> f32 {
+    let delta = (to - from + 180.0).rem_euclid(360.0) - 180.0;
+    (from + delta * t).rem_euclid(360.0)
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+impl Color {
+    /// This color in another color space; `None` for [`Color::Default`]
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::colorspace::{Lab, Rgb};
+    /// use make_colors::Color;
+    ///
+    /// let lab: Lab = Color::Rgb(255, 255, 255).convert().unwrap();
+    /// assert!((lab.l - 100.0).abs() < 0.01);
+    /// assert_eq!(Color::Default.convert::<Rgb>(), None);
+    /// ```
+    pub fn convert<T: From<Rgb>>(&self) -> Option<T> {
+        let (r, g, b) = self.to_rgb()?;
+        Some(Rgb::from_u8(r, g, b).into())
+    }
+
+    /// Apply `f` in HSL space
+    fn map_hsl(self, f: impl FnOnce(&mut Hsl)) -> Color {
+        match self.convert::<Hsl>() {
+            Some(mut hsl) => {
+                f(&mut hsl);
+                hsl.into()
+            }
+            None => self,
+        }
+    }
+
+    /// Raise HSL lightness by `amount` (`0.0..=1.0`)
+    pub fn lighten(self, amount: f32) -> Color {
+        self.map_hsl(|hsl| hsl.l = (hsl.l + amount).clamp(0.0, 1.0))
+    }
+
+    /// Lower HSL lightness by `amount` (`0.0..=1.0`)
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Raise HSL saturation by `amount` (`0.0..=1.0`)
+    pub fn saturate(self, amount: f32) -> Color {
+        self.map_hsl(|hsl| hsl.s = (hsl.s + amount).clamp(0.0, 1.0))
+    }
+
+    /// Lower HSL saturation by `amount` (`0.0..=1.0`)
+    pub fn desaturate(self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Turn the hue by `degrees`; negative values turn the other way
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        self.map_hsl(|hsl| hsl.h = (hsl.h + degrees).rem_euclid(360.0))
+    }
+
+    /// The RGB complement
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 136, 0).invert(), Color::Rgb(0, 119, 255));
+    /// ```
+    pub fn invert(self) -> Color {
+        match self.to_rgb() {
+            Some((r, g, b)) => Color::Rgb(255 - r, 255 - g, 255 - b),
+            None => self,
+        }
+    }
+
+    /// A gray of the same perceived lightness (OKLab `L`)
+    pub fn grayscale(self) -> Color {
+        match self.convert::<Oklab>() {
+            Some(lab) => Oklab { a: 0.0, b: 0.0, ..lab }.into(),
+            None => self,
+        }
+    }
+
+    /// Blend with `other` in sRGB; `t = 0.0` gives `self`, `t = 1.0` gives `other`
+    pub fn mix(self, other: Color, t: f32) -> Color {
+        self.mix_in(other, t, ColorSpace::Rgb)
+    }
+
+    /// Blend with `other` in the given color space
+    ///
+    /// If either color is [`Color::Default`], the nearer one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::colorspace::ColorSpace;
+    /// use make_colors::Color;
+    ///
+    /// let (red, blue) = (Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255));
+    /// assert_eq!(red.mix_in(blue, 0.5, ColorSpace::Rgb), Color::Rgb(128, 0, 128));
+    /// assert_eq!(red.mix_in(blue, 0.5, ColorSpace::Hsl), Color::Rgb(255, 0, 255));
+    /// ```
+    pub fn mix_in(self, other: Color, t: f32, space: ColorSpace) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (Some(from), Some(to)) = (self.convert::<Rgb>(), other.convert::<Rgb>()) else {
+            return if t < 0.5 { self } else { other };
+        };
+
+        match space {
+            ColorSpace::Rgb => Rgb::new(lerp(from.r, to.r, t), lerp(from.g, to.g, t), lerp(from.b, to.b, t)).into(),
+            ColorSpace::Hsl => {
+                let (mut from, mut to) = (Hsl::from(from), Hsl::from(to));
+                // A gray has no hue of its own, so take the other one
+                if from.s == 0.0 {
+                    from.h = to.h;
+                } else if to.s == 0.0 {
+                    to.h = from.h;
+                }
+                Hsl {
+                    h: lerp_hue(from.h, to.h, t),
+                    s: lerp(from.s, to.s, t),
+                    l: lerp(from.l, to.l, t),
+                }
+                .into()
+            }
+            ColorSpace::Oklab => {
+                let (from, to) = (Oklab::from(from), Oklab::from(to));
+                Oklab {
+                    l: lerp(from.l, to.l, t),
+                    a: lerp(from.a, to.a, t),
+                    b: lerp(from.b, to.b, t),
+                }
+                .into()
+            }
+            ColorSpace::Oklch => {
+                let (mut from, mut to) = (Oklch::from(from), Oklch::from(to));
+                if from.c < 1e-4 {
+                    from.h = to.h;
+                } else if to.c < 1e-4 {
+                    to.h = from.h;
+                }
+                Oklch {
+                    l: lerp(from.l, to.l, t),
+                    c: lerp(from.c, to.c, t),
+                    h: lerp_hue(from.h, to.h, t),
+                }
+                .into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> impl Iterator<Item = (u8, u8, u8)> {
+        (0..=255u8).step_by(17).flat_map(|r| {
+            (0..=255u8).step_by(17).flat_map(move |g| (0..=255u8).step_by(17).map(move |b| (r, g, b)))
+        })
+    }
+
+    #[test]
+    fn test_round_trips() {
+        fn round_trip<T: From<Rgb> + Into<Rgb>>(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+            let value: T = Rgb::from_u8(r, g, b).into();
+            value.into().to_u8()
+        }
+
+        for (r, g, b) in samples() {
+            assert_eq!(round_trip::<Hsl>(r, g, b), (r, g, b));
+            assert_eq!(round_trip::<Hsv>(r, g, b), (r, g, b));
+            assert_eq!(round_trip::<Lab>(r, g, b), (r, g, b));
+            assert_eq!(round_trip::<Oklab>(r, g, b), (r, g, b));
+            assert_eq!(round_trip::<Oklch>(r, g, b), (r, g, b), "{:?}", (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+
+        let hsl = Hsl::from(Rgb::from_u8(255, 0, 0));
+        assert_eq!((hsl.h, hsl.s, hsl.l), (0.0, 1.0, 0.5));
+        let hsv = Hsv::from(Rgb::from_u8(0, 128, 255));
+        assert!(close(hsv.h, 209.88) && close(hsv.s, 1.0) && close(hsv.v, 1.0));
+
+        let lab = Lab::from(Rgb::from_u8(255, 0, 0));
+        assert!(close(lab.l, 53.24) && (lab.a - 80.09).abs() < 0.05 && (lab.b - 67.20).abs() < 0.05, "{:?}", lab);
+
+        let lch = Oklch::from(Rgb::from_u8(255, 0, 0));
+        assert!(close(lch.l, 0.628) && close(lch.c, 0.2577) && (lch.h - 29.23).abs() < 0.05, "{:?}", lch);
+        assert_eq!(Color::from(Hsl { h: 390.0, s: 1.0, l: 0.5 }), Color::Rgb(255, 128, 0));
+    }
+
+    #[test]
+    fn test_manipulations() {
+        let orange = Color::Rgb(255, 136, 0);
+        assert_eq!(orange.lighten(0.2), Color::Rgb(255, 184, 102));
+        assert_eq!(orange.darken(1.0), Color::Rgb(0, 0, 0));
+        assert_eq!(orange.desaturate(1.0), Color::Rgb(128, 128, 128));
+        assert_eq!(Color::Rgb(191, 64, 64).saturate(0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::Rgb(255, 0, 0).rotate_hue(-120.0), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::BrightRed.invert(), Color::Rgb(0, 255, 255));
+
+        let gray = orange.grayscale();
+        let (r, g, b) = gray.to_rgb().unwrap();
+        assert!(r == g && g == b);
+        assert_eq!(Color::Rgb(255, 255, 255).grayscale(), Color::Rgb(255, 255, 255));
+
+        assert_eq!(Color::Default.lighten(0.5), Color::Default);
+        assert_eq!(Color::Default.mix(Color::Red, 0.7), Color::Red);
+        assert_eq!(Color::Red.mix(Color::Blue, 0.0), Color::Rgb(205, 0, 0));
+        assert_eq!(Color::Rgb(0, 0, 0).mix_in(Color::Rgb(255, 0, 0), 0.5, ColorSpace::Oklch), Color::Rgb(99, 0, 0));
+    }
+}
@@ -0,0 +1,225 @@
+// File: src\contrast.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Luminance, WCAG and APCA contrast, and readable foreground selection.
+// License: MIT
+
+//! Contrast and readability.
+//!
+//! [`contrast_ratio`] is the WCAG 2.x ratio between two colors, from `1.0`
+//! (identical luminance) to `21.0` (black on white); WCAG asks for at least
+//! [`AA`] for body text and [`AAA`] for enhanced contrast. [`apca_contrast`]
+//! is the APCA lightness contrast `Lc` proposed for WCAG 3, which takes
+//! polarity into account. Named and 256-color values are measured with the
+//! xterm palette; [`Color::Default`] has no known value, so functions given
+//! one return `None`.
+//!
+//! [`best_foreground`] picks black or white text for a background, and
+//! [`ensure_contrast`] nudges a chosen foreground just far enough towards
+//! black or white to reach a minimum ratio. [`ColorBuilder::min_contrast`]
+//! applies the latter when text is built.
+//!
+//! [`ColorBuilder::min_contrast`]: crate::ColorBuilder::min_contrast
+//!
+//! # Examples
+//! ```
+//! use make_colors::contrast::{self, AA};
+//! use make_colors::Color;
+//!
+//! let bg = Color::Rgb(255, 200, 0);
+//! assert_eq!(contrast::best_foreground(bg), Color::Rgb(0, 0, 0));
+//!
+//! let fg = contrast::ensure_contrast(Color::Rgb(255, 255, 255), bg, AA);
+//! assert!(contrast::contrast_ratio(fg, bg).unwrap() >= AA);
+//! ```
+
+use crate::colorspace::{ColorSpace, Rgb};
+use crate::Color;
+
+/// WCAG level AA minimum contrast ratio for normal text
+pub const AA: f32 = 4.5;
+
+/// WCAG level AA minimum contrast ratio for large text
+pub const AA_LARGE: f32 = 3.0;
+
+/// WCAG level AAA minimum contrast ratio for normal text
+pub const AAA: f32 = 7.0;
+
+const BLACK: Color = Color::Rgb(0, 0, 0);
+const WHITE: Color = Color::Rgb(255, 255, 255);
+
+/// WCAG relative luminance, from `0.0` for black to `1.0` for white
+///
+/// # Examples
+/// ```
+/// use make_colors::contrast::relative_luminance;
+/// use make_colors::Color;
+///
+/// assert_eq!(relative_luminance(Color::Rgb(0, 0, 0)), Some(0.0));
+/// assert!(relative_luminance(Color::Rgb(255, 255, 255)).unwrap() > 0.999);
+/// assert_eq!(relative_luminance(Color::Default), None);
+/// ```
+pub fn relative_luminance(color: Color) -> Option<f32> {
+    let [r, g, b] = color.convert::<Rgb>()?.to_linear();
+    Some(0.2126 * r + 0.7152 * g + 0.0722 * b)
+}
+
+/// WCAG 2.x contrast ratio between two colors, in `1.0..=21.0`
+///
+/// The ratio is symmetric, so the order of the colors does not matter.
+pub fn contrast_ratio(a: Color, b: Color) -> Option<f32> {
+    let (a, b) = (relative_luminance(a)?, relative_luminance(b)?);
+    let (light, dark) = if a > b { (a, b) } else { (b, a) };
+    Some((light + 0.05) / (dark + 0.05))
+}
+
+/// APCA lightness contrast (`Lc`) of `text` on `background`
+///
+/// Positive for dark text on a light background and negative for light
+/// text on a dark one. An absolute value of 75 or more is recommended for
+/// body text, 60 for larger text and 45 for headlines.
+///
+/// # Examples
+/// ```
+/// use make_colors::contrast::apca_contrast;
+/// use make_colors::Color;
+///
+/// let (black, white) = (Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+/// assert_eq!(apca_contrast(black, white).unwrap().round(), 106.0);
+/// assert_eq!(apca_contrast(white, black).unwrap().round(), -108.0);
+/// ```
+pub fn apca_contrast(text: Color, background: Color) -> Option<f32> {
+    // Screen luminance with a soft clamp near black (APCA 0.0.98G)
+    let luminance = |color: Color| -> Option<f32> {
+        let Rgb { r, g, b } = color.convert()?;
+        let y = 0.212_672_9 * r.powf(2.4) + 0.715_152_2 * g.powf(2.4) + 0.072_175 * b.powf(2.4);
+        Some(if y < 0.022 { y + (0.022 - y).powf(1.414) } else { y })
+    };
+    let (text, background) = (luminance(text)?, luminance(background)?);
+
+    if (background - text).abs() < 0.0005 {
+        return Some(0.0);
+    }
+    let lc = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    Some(lc * 100.0)
+}
+
+/// Black or white, whichever reads better on `background`
+///
+/// Returns [`Color::Default`] for a default background, whose color is
+/// unknown.
+pub fn best_foreground(background: Color) -> Color {
+    best_foreground_from(background, &[BLACK, WHITE]).unwrap_or_default()
+}
+
+/// The entry of `palette` with the highest contrast ratio against `background`
+///
+/// Entries without a known color are skipped. Returns `None` if nothing
+/// can be compared.
+///
+/// # Examples
+/// ```
+/// use make_colors::contrast::best_foreground_from;
+/// use make_colors::Color;
+///
+/// let palette = [Color::Yellow, Color::Blue, Color::BrightCyan];
+/// assert_eq!(best_foreground_from(Color::Rgb(20, 20, 20), &palette), Some(Color::BrightCyan));
+/// ```
+pub fn best_foreground_from(background: Color, palette: &[Color]) -> Option<Color> {
+    palette
+        .iter()
+        .filter_map(|&color| Some((color, contrast_ratio(color, background)?)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(color, _)| color)
+}
+
+/// `foreground`, moved towards black or white until its contrast ratio
+/// against `background` is at least `min_ratio`
+///
+/// The foreground is blended in OKLab as little as needed, so its hue is
+/// kept as far as possible. If neither direction reaches `min_ratio`, the
+/// best of black and white is returned. Colors without a known value are
+/// returned unchanged.
+pub fn ensure_contrast(foreground: Color, background: Color, min_ratio: f32) -> Color {
+    let Some(ratio) = contrast_ratio(foreground, background) else {
+        return foreground;
+    };
+    if ratio >= min_ratio {
+        return foreground;
+    }
+
+    let ratio_towards = |target: Color, t: f32| {
+        let color = foreground.mix_in(target, t, ColorSpace::Oklab);
+        (color, contrast_ratio(color, background).unwrap_or(1.0))
+    };
+
+    let mut candidates = Vec::new();
+    for target in [BLACK, WHITE] {
+        if ratio_towards(target, 1.0).1 < min_ratio {
+            continue;
+        }
+        // Smallest blend that is still enough
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..16 {
+            let middle = (low + high) / 2.0;
+            if ratio_towards(target, middle).1 >= min_ratio {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        candidates.push((high, ratio_towards(target, high).0));
+    }
+
+    candidates
+        .into_iter()
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map_or_else(|| best_foreground(background), |(_, color)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio(BLACK, WHITE).unwrap() - 21.0).abs() < 1e-4);
+        assert_eq!(contrast_ratio(Color::Red, Color::Red), Some(1.0));
+        let gray = contrast_ratio(Color::Rgb(0x77, 0x77, 0x77), WHITE).unwrap();
+        assert!((gray - 4.48).abs() < 0.01, "{}", gray);
+        assert_eq!(contrast_ratio(Color::Default, WHITE), None);
+
+        assert_eq!(apca_contrast(Color::Rgb(0x88, 0x88, 0x88), Color::Rgb(0x88, 0x88, 0x88)), Some(0.0));
+        let lc = apca_contrast(Color::Rgb(0x88, 0x88, 0x88), WHITE).unwrap();
+        assert!((lc - 63.06).abs() < 0.1, "{}", lc);
+    }
+
+    #[test]
+    fn test_best_foreground() {
+        assert_eq!(best_foreground(Color::Rgb(0, 0, 128)), WHITE);
+        assert_eq!(best_foreground(Color::BrightYellow), BLACK);
+        assert_eq!(best_foreground(Color::Default), Color::Default);
+        assert_eq!(best_foreground_from(WHITE, &[Color::Default]), None);
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let bg = Color::Rgb(30, 30, 30);
+        let fg = Color::Rgb(60, 60, 200);
+        let adjusted = ensure_contrast(fg, bg, AA);
+        let ratio = contrast_ratio(adjusted, bg).unwrap();
+        assert!((AA..AA + 0.3).contains(&ratio), "{:?} {}", adjusted, ratio);
+        let (r, g, b) = adjusted.to_rgb().unwrap();
+        assert!(b > r && b > g, "{:?}", adjusted);
+
+        assert_eq!(ensure_contrast(WHITE, bg, AA), WHITE);
+        assert_eq!(ensure_contrast(Color::Rgb(119, 119, 119), Color::Rgb(128, 128, 128), 21.0), BLACK);
+        assert_eq!(ensure_contrast(Color::Default, bg, AA), Color::Default);
+    }
+}
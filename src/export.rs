@@ -0,0 +1,753 @@
+// File: src\export.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Export ANSI-styled text to HTML and SVG.
+// License: MIT
+
+//! Exporting colored output.
+//!
+//! [`to_html`] converts text containing SGR escape sequences into HTML, so
+//! terminal output can be pasted into CI reports and documentation. Styles
+//! are written either inline or as CSS classes (see [`HtmlMode`]), and the
+//! result is either a `<pre>` fragment or a complete page. Text content is
+//! always escaped, so captured output cannot inject markup.
+//!
+//! [`to_svg`] draws the same text as a terminal window screenshot, laid out
+//! on a monospace grid, which makes screenshots reproducible without a
+//! real terminal.
+//!
+//! # Examples
+//! ```
+//! use make_colors::export::{self, HtmlOptions};
+//!
+//! let html = export::to_html("\x1b[1;31mfailed\x1b[0m: <none>", &HtmlOptions::default());
+//! assert!(html.contains("<span style=\"color:#cd0000;font-weight:bold\">failed</span>"));
+//! assert!(html.contains("&lt;none&gt;"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::quantize::{ansi256_to_rgb, ANSI16_PALETTE};
+use crate::spans::UNDERLINES;
+use crate::{spans, width, Attributes, Color, Style};
+
+/// How styles are written into the HTML
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HtmlMode {
+    /// A `style` attribute on every span
+    #[default]
+    InlineStyles,
+    /// Classes for standard colors and attributes, defined by [`stylesheet`]
+    ///
+    /// 256-color and true color values, as well as several text decorations
+    /// on the same span, cannot be expressed as classes and stay inline.
+    Classes,
+}
+
+/// Options for [`to_html`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlOptions {
+    pub mode: HtmlMode,
+    /// Prefix of every generated class name
+    pub class_prefix: String,
+    /// Text color where no foreground is set
+    pub foreground: Color,
+    /// Page color where no background is set
+    pub background: Color,
+    /// Write a complete HTML document instead of a `<pre>` fragment
+    pub full_page: bool,
+    /// Title of the full page document
+    pub title: String,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        HtmlOptions {
+            mode: HtmlMode::InlineStyles,
+            class_prefix: "mc".to_string(),
+            foreground: Color::White,
+            background: Color::Black,
+            full_page: false,
+            title: "make_colors output".to_string(),
+        }
+    }
+}
+
+impl HtmlOptions {
+    pub fn mode(mut self, mode: HtmlMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the default text and page colors
+    pub fn colors(mut self, foreground: Color, background: Color) -> Self {
+        self.foreground = foreground;
+        self.background = background;
+        self
+    }
+
+    /// Write a complete HTML document with the given title
+    pub fn full_page(mut self, title: &str) -> Self {
+        self.full_page = true;
+        self.title = title.to_string();
+        self
+    }
+
+    /// Class prefix with anything that is not a letter, digit, `-` or `_` removed
+    fn prefix(&self) -> String {
+        self.class_prefix
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect()
+    }
+}
+
+const COLOR_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright-black",
+    "bright-red",
+    "bright-green",
+    "bright-yellow",
+    "bright-blue",
+    "bright-magenta",
+    "bright-cyan",
+    "bright-white",
+];
+
+/// Text decorations, combined into one `text-decoration` property
+const DECORATIONS: [(Attributes, &str); 7] = [
+    (Attributes::UNDERLINE, "underline"),
+    (Attributes::DOUBLE_UNDERLINE, "underline double"),
+    (Attributes::CURLY_UNDERLINE, "underline wavy"),
+    (Attributes::DOTTED_UNDERLINE, "underline dotted"),
+    (Attributes::DASHED_UNDERLINE, "underline dashed"),
+    (Attributes::STRIKETHROUGH, "line-through"),
+    (Attributes::OVERLINE, "overline"),
+];
+
+/// CSS of every attribute other than reverse, which swaps colors instead
+const ATTRIBUTE_CSS: [(Attributes, &str); 8] = [
+    (Attributes::BOLD, "font-weight:bold"),
+    (Attributes::DIM, "opacity:0.5"),
+    (Attributes::ITALIC, "font-style:italic"),
+    (Attributes::BLINK, "animation:{p}-blink 1s step-end infinite"),
+    (Attributes::RAPID_BLINK, "animation:{p}-blink 0.4s step-end infinite"),
+    (Attributes::HIDDEN, "visibility:hidden"),
+    (Attributes::FRAMED, "outline:1px solid"),
+    (Attributes::ENCIRCLED, "border:1px solid;border-radius:0.6em"),
+];
+
+fn css_color(color: Color) -> Option<String> {
+    let (r, g, b) = color.to_rgb()?;
+    Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+/// Escape text for use in HTML content and attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Convert ANSI-styled text to HTML
+///
+/// # Examples
+/// ```
+/// use make_colors::export::{self, HtmlMode, HtmlOptions};
+///
+/// let options = HtmlOptions::default().mode(HtmlMode::Classes).full_page("Build log");
+/// let page = export::to_html("\x1b[32mok\x1b[0m", &options);
+/// assert!(page.starts_with("<!DOCTYPE html>"));
+/// assert!(page.contains("<span class=\"mc-fg-green\">ok</span>"));
+/// ```
+pub fn to_html(text: &str, options: &HtmlOptions) -> String {
+    let prefix = options.prefix();
+    let mut body = String::new();
+
+    let _ = write!(body, "<pre class=\"{}-output\"", prefix);
+    if options.mode == HtmlMode::InlineStyles {
+        body.push_str(" style=\"");
+        body.push_str(&output_css(options));
+        body.push('"');
+    }
+    body.push('>');
+
+    for span in spans::parse(text) {
+        let (classes, css) = span_attributes(span.style.fg, span.style.bg, span.style.attrs, options, &prefix);
+        let text = escape_html(&span.text);
+        if classes.is_empty() && css.is_empty() {
+            body.push_str(&text);
+            continue;
+        }
+
+        body.push_str("<span");
+        if !classes.is_empty() {
+            let _ = write!(body, " class=\"{}\"", classes.join(" "));
+        }
+        if !css.is_empty() {
+            let _ = write!(body, " style=\"{}\"", css.join(";"));
+        }
+        let _ = write!(body, ">{}</span>", text);
+    }
+    body.push_str("</pre>");
+
+    if !options.full_page {
+        return body;
+    }
+
+    let css = match options.mode {
+        HtmlMode::InlineStyles => blink_keyframes(&prefix),
+        HtmlMode::Classes => stylesheet(options),
+    };
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body style=\"background-color:{}\">\n{}\n</body>\n</html>\n",
+        escape_html(&options.title),
+        css,
+        css_color(options.background).unwrap_or_default(),
+        body
+    )
+}
+
+/// CSS defining the classes written in [`HtmlMode::Classes`]
+///
+/// Full page output already embeds it; fragments need it added to the
+/// surrounding document.
+pub fn stylesheet(options: &HtmlOptions) -> String {
+    let p = options.prefix();
+    let mut css = String::new();
+
+    let _ = writeln!(css, ".{}-output {{ {} }}", p, output_css(options));
+    for (i, name) in COLOR_NAMES.iter().enumerate() {
+        let color = css_color(Color::Ansi256(i as u8)).unwrap_or_default();
+        let _ = writeln!(css, ".{}-fg-{} {{ color: {} }}", p, name, color);
+        let _ = writeln!(css, ".{}-bg-{} {{ background-color: {} }}", p, name, color);
+    }
+    for (attr, rule) in ATTRIBUTE_CSS {
+        let _ = writeln!(css, ".{}-{} {{ {} }}", p, class_name(attr), rule.replace("{p}", &p));
+    }
+    for (attr, decoration) in DECORATIONS {
+        let _ = writeln!(css, ".{}-{} {{ text-decoration: {} }}", p, class_name(attr), decoration);
+    }
+    css.push_str(&blink_keyframes(&p));
+
+    css
+}
+
+fn blink_keyframes(prefix: &str) -> String {
+    format!("@keyframes {}-blink {{ 50% {{ opacity: 0 }} }}\n", prefix)
+}
+
+fn output_css(options: &HtmlOptions) -> String {
+    let mut css = String::new();
+    if let Some(color) = css_color(options.foreground) {
+        let _ = write!(css, "color:{};", color);
+    }
+    if let Some(color) = css_color(options.background) {
+        let _ = write!(css, "background-color:{};", color);
+    }
+    css.push_str("font-family:monospace");
+    css
+}
+
+fn class_name(attr: Attributes) -> String {
+    attr.to_string().replace('_', "-")
+}
+
+/// Classes and inline declarations of one span
+fn span_attributes(
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attrs: Attributes,
+    options: &HtmlOptions,
+    prefix: &str,
+) -> (Vec<String>, Vec<String>) {
+    let mut classes = Vec::new();
+    let mut css = Vec::new();
+    let use_classes = options.mode == HtmlMode::Classes;
+
+    let (fg, bg) = if attrs.contains(Attributes::REVERSE) {
+        (
+            Some(bg.unwrap_or(options.background)),
+            Some(fg.unwrap_or(options.foreground)),
+        )
+    } else {
+        (fg, bg)
+    };
+
+    for (color, layer, property) in [(fg, "fg", "color"), (bg, "bg", "background-color")] {
+        let Some(color) = color else { continue };
+        match color.ansi16_index() {
+            Some(index) if use_classes => classes.push(format!("{}-{}-{}", prefix, layer, COLOR_NAMES[index as usize])),
+            _ => {
+                if let Some(value) = css_color(color) {
+                    css.push(format!("{}:{}", property, value));
+                }
+            }
+        }
+    }
+
+    for (attr, rule) in ATTRIBUTE_CSS.iter().filter(|(attr, _)| attrs.contains(*attr)) {
+        if use_classes {
+            classes.push(format!("{}-{}", prefix, class_name(*attr)));
+        } else {
+            css.push(rule.replace("{p}", prefix));
+        }
+    }
+
+    let decorations: Vec<_> = DECORATIONS.iter().filter(|(attr, _)| attrs.contains(*attr)).collect();
+    match decorations.as_slice() {
+        [] => {}
+        [(attr, _)] if use_classes => classes.push(format!("{}-{}", prefix, class_name(*attr))),
+        _ => {
+            let lines: Vec<&str> = decorations.iter().map(|(_, decoration)| *decoration).collect();
+            css.push(format!("text-decoration:{}", merge_decorations(&lines)));
+        }
+    }
+
+    (classes, css)
+}
+
+/// Merge `text-decoration` values; CSS allows one line style for all lines
+fn merge_decorations(values: &[&str]) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut style = None;
+    for value in values {
+        for word in value.split(' ') {
+            match word {
+                "underline" | "line-through" | "overline" => {
+                    if !lines.contains(&word) {
+                        lines.push(word);
+                    }
+                }
+                _ => style = style.or(Some(word)),
+            }
+        }
+    }
+    lines.extend(style);
+    lines.join(" ")
+}
+
+/// Colors of a terminal window used by [`to_svg`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerminalTheme {
+    pub foreground: (u8, u8, u8),
+    pub background: (u8, u8, u8),
+    /// The 16 standard colors, in `Color::Black..BrightWhite` order
+    pub palette: [(u8, u8, u8); 16],
+}
+
+impl TerminalTheme {
+    /// xterm's default colors
+    pub const XTERM: TerminalTheme = TerminalTheme {
+        foreground: (229, 229, 229),
+        background: (0, 0, 0),
+        palette: ANSI16_PALETTE,
+    };
+
+    /// Solarized dark
+    pub const SOLARIZED_DARK: TerminalTheme = TerminalTheme {
+        foreground: (131, 148, 150),
+        background: (0, 43, 54),
+        palette: [
+            (7, 54, 66),
+            (220, 50, 47),
+            (133, 153, 0),
+            (181, 137, 0),
+            (38, 139, 210),
+            (211, 54, 130),
+            (42, 161, 152),
+            (238, 232, 213),
+            (0, 43, 54),
+            (203, 75, 22),
+            (88, 110, 117),
+            (101, 123, 131),
+            (131, 148, 150),
+            (108, 113, 196),
+            (147, 161, 161),
+            (253, 246, 227),
+        ],
+    };
+
+    /// RGB value of `color`, with `None` and [`Color::Default`] resolving to `default`
+    fn resolve(&self, color: Option<Color>, default: (u8, u8, u8)) -> (u8, u8, u8) {
+        match color {
+            Some(Color::Rgb(r, g, b)) => (r, g, b),
+            Some(Color::Ansi256(n)) if n >= 16 => ansi256_to_rgb(n),
+            Some(Color::Ansi256(n)) => self.palette[n as usize],
+            Some(named) => named.ansi16_index().map_or(default, |i| self.palette[i as usize]),
+            None => default,
+        }
+    }
+}
+
+impl Default for TerminalTheme {
+    fn default() -> Self {
+        TerminalTheme::XTERM
+    }
+}
+
+/// Options for [`to_svg`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    pub theme: TerminalTheme,
+    pub font_family: String,
+    /// Font size in pixels
+    pub font_size: f32,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
+    /// Space around the text in pixels
+    pub padding: f32,
+    /// Draw a title bar with window buttons
+    pub window_chrome: bool,
+    /// Title shown in the title bar
+    pub title: String,
+    /// Minimum width of the grid in columns
+    pub columns: usize,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            theme: TerminalTheme::XTERM,
+            font_family: "'DejaVu Sans Mono', Menlo, Consolas, monospace".to_string(),
+            font_size: 14.0,
+            line_height: 1.4,
+            padding: 16.0,
+            window_chrome: true,
+            title: String::new(),
+            columns: 0,
+        }
+    }
+}
+
+impl SvgOptions {
+    pub fn theme(mut self, theme: TerminalTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the font family and size in pixels
+    pub fn font(mut self, family: &str, size: f32) -> Self {
+        self.font_family = family.to_string();
+        self.font_size = size;
+        self
+    }
+
+    /// Show or hide the title bar
+    pub fn window_chrome(mut self, enabled: bool) -> Self {
+        self.window_chrome = enabled;
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+}
+
+/// Height of the title bar in pixels
+const CHROME_HEIGHT: f32 = 32.0;
+const TAB_WIDTH: usize = 8;
+
+/// A run of text placed on the grid
+struct Cell {
+    row: usize,
+    column: usize,
+    width: usize,
+    text: String,
+    style: Style,
+}
+
+/// Render ANSI-styled text as a self-contained SVG terminal window
+///
+/// Text is laid out on a monospace grid where wide characters take two
+/// columns, so the output only depends on the input and the options.
+///
+/// # Examples
+/// ```
+/// use make_colors::export::{self, SvgOptions, TerminalTheme};
+///
+/// let options = SvgOptions::default().theme(TerminalTheme::SOLARIZED_DARK).title("demo");
+/// let svg = export::to_svg("\x1b[32m✔\x1b[0m tests passed\n", &options);
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains("tests passed"));
+/// ```
+pub fn to_svg(text: &str, options: &SvgOptions) -> String {
+    let (cells, rows, columns) = layout(text);
+    let mut columns = columns.max(options.columns);
+    if options.window_chrome && !options.title.is_empty() {
+        // Keep the centered title clear of the window buttons
+        columns = columns.max(width::text_width(&options.title) + 16);
+    }
+
+    let cell_width = options.font_size * 0.6;
+    let line_height = options.font_size * options.line_height;
+    let top = options.padding + if options.window_chrome { CHROME_HEIGHT } else { 0.0 };
+    let width = options.padding * 2.0 + columns as f32 * cell_width;
+    let height = top + options.padding + rows as f32 * line_height;
+    let theme = &options.theme;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"{}\" font-size=\"{}\">",
+        escape_html(&options.font_family),
+        num(options.font_size),
+        w = num(width),
+        h = num(height),
+    );
+    let _ = writeln!(
+        svg,
+        "<rect width=\"{}\" height=\"{}\" rx=\"8\" fill=\"{}\"/>",
+        num(width),
+        num(height),
+        hex(theme.background)
+    );
+
+    if options.window_chrome {
+        for (i, color) in ["#ff5f56", "#ffbd2e", "#27c93f"].iter().enumerate() {
+            let _ = writeln!(svg, "<circle cx=\"{}\" cy=\"16\" r=\"6\" fill=\"{}\"/>", 20 + i * 20, color);
+        }
+        if !options.title.is_empty() {
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"20\" text-anchor=\"middle\" fill=\"{}\" opacity=\"0.7\">{}</text>",
+                num(width / 2.0),
+                hex(theme.foreground),
+                escape_html(&options.title)
+            );
+        }
+    }
+
+    for cell in &cells {
+        let x = options.padding + cell.column as f32 * cell_width;
+        let y = top + cell.row as f32 * line_height;
+        let span_width = cell.width as f32 * cell_width;
+        let attrs = cell.style.attrs;
+
+        let mut fg = theme.resolve(cell.style.fg, theme.foreground);
+        let mut bg = cell.style.bg.map(|_| theme.resolve(cell.style.bg, theme.background));
+        if attrs.contains(Attributes::REVERSE) {
+            let reversed_fg = bg.unwrap_or(theme.background);
+            bg = Some(fg);
+            fg = reversed_fg;
+        }
+
+        if let Some(bg) = bg {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                num(x),
+                num(y),
+                num(span_width),
+                num(line_height),
+                hex(bg)
+            );
+        }
+        if attrs.intersects(Attributes::FRAMED | Attributes::ENCIRCLED) {
+            let radius = if attrs.contains(Attributes::ENCIRCLED) { line_height / 2.0 } else { 0.0 };
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" fill=\"none\" stroke=\"{}\"/>",
+                num(x),
+                num(y),
+                num(span_width),
+                num(line_height),
+                num(radius),
+                hex(fg)
+            );
+        }
+        if attrs.contains(Attributes::HIDDEN) || cell.text.trim().is_empty() {
+            continue;
+        }
+
+        let _ = write!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" fill=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\" xml:space=\"preserve\"",
+            num(x),
+            num(y + line_height * 0.75),
+            hex(fg),
+            num(span_width)
+        );
+        if attrs.contains(Attributes::BOLD) {
+            svg.push_str(" font-weight=\"bold\"");
+        }
+        if attrs.contains(Attributes::ITALIC) {
+            svg.push_str(" font-style=\"italic\"");
+        }
+        if attrs.contains(Attributes::DIM) {
+            svg.push_str(" opacity=\"0.5\"");
+        }
+        let decorations = svg_decorations(attrs);
+        if !decorations.is_empty() {
+            let _ = write!(svg, " text-decoration=\"{}\"", decorations);
+        }
+        let _ = writeln!(svg, ">{}</text>", escape_html(&cell.text));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Place styled text on the grid, returning the cells, rows and columns used
+fn layout(text: &str) -> (Vec<Cell>, usize, usize) {
+    let mut cells = Vec::new();
+    let (mut row, mut column, mut columns) = (0, 0, 0);
+
+    for span in spans::parse(text) {
+        let mut current = String::new();
+        let mut start = column;
+
+        for grapheme in width::graphemes(&span.text) {
+            match grapheme {
+                "\n" | "\r\n" => {
+                    push_cell(&mut cells, row, start, column, &mut current, span.style);
+                    row += 1;
+                    column = 0;
+                    start = 0;
+                }
+                "\r" => {}
+                "\t" => {
+                    let next = (column / TAB_WIDTH + 1) * TAB_WIDTH;
+                    current.push_str(&" ".repeat(next - column));
+                    column = next;
+                }
+                _ => {
+                    current.push_str(grapheme);
+                    column += width::grapheme_width(grapheme);
+                }
+            }
+            columns = columns.max(column);
+        }
+        push_cell(&mut cells, row, start, column, &mut current, span.style);
+    }
+
+    let rows = if column > 0 { row + 1 } else { row.max(1) };
+    (cells, rows, columns)
+}
+
+fn push_cell(cells: &mut Vec<Cell>, row: usize, start: usize, end: usize, text: &mut String, style: Style) {
+    if !text.is_empty() {
+        cells.push(Cell {
+            row,
+            column: start,
+            width: end - start,
+            text: std::mem::take(text),
+            style,
+        });
+    }
+}
+
+fn svg_decorations(attrs: Attributes) -> String {
+    let mut lines = Vec::new();
+    if attrs.intersects(UNDERLINES) {
+        lines.push("underline");
+    }
+    if attrs.contains(Attributes::STRIKETHROUGH) {
+        lines.push("line-through");
+    }
+    if attrs.contains(Attributes::OVERLINE) {
+        lines.push("overline");
+    }
+    lines.join(" ")
+}
+
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Format a coordinate with at most two decimals and no trailing zeros
+fn num(value: f32) -> String {
+    let formatted = format!("{:.2}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_html() {
+        let html = to_html(
+            "\x1b[38;2;255;136;0;48;5;17;3;4;9mx\x1b[0m \x1b[7my\x1b[0m",
+            &HtmlOptions::default(),
+        );
+        assert_eq!(
+            html,
+            "<pre class=\"mc-output\" style=\"color:#e5e5e5;background-color:#000000;font-family:monospace\">\
+             <span style=\"color:#ff8800;background-color:#00005f;font-style:italic;text-decoration:underline line-through\">x</span> \
+             <span style=\"color:#000000;background-color:#e5e5e5\">y</span></pre>"
+        );
+    }
+
+    #[test]
+    fn test_class_html() {
+        let options = HtmlOptions {
+            class_prefix: "t\"><script>".to_string(),
+            ..HtmlOptions::default().mode(HtmlMode::Classes)
+        };
+        let html = to_html("\x1b[1;91;44;21mx\x1b[0m\x1b[38;5;208my", &options);
+        assert_eq!(
+            html,
+            "<pre class=\"tscript-output\">\
+             <span class=\"tscript-fg-bright-red tscript-bg-blue tscript-bold tscript-double-underline\">x</span>\
+             <span style=\"color:#ff8700\">y</span></pre>"
+        );
+        let css = stylesheet(&options);
+        assert!(css.contains(".tscript-fg-bright-red { color: #ff0000 }"));
+        assert!(css.contains(".tscript-curly-underline { text-decoration: underline wavy }"));
+    }
+
+    #[test]
+    fn test_escaping_and_full_page() {
+        let options = HtmlOptions::default().full_page("<b>&</b>");
+        let page = to_html("\x1b[31m<script>alert('x')</script>\x1b[0m", &options);
+        assert!(page.contains("<title>&lt;b&gt;&amp;&lt;/b&gt;</title>"));
+        assert!(page.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+        assert!(!page.contains("<script>"));
+        assert!(page.contains("@keyframes mc-blink"));
+    }
+
+    #[test]
+    fn test_svg_layout() {
+        let (cells, rows, columns) = layout("ab\x1b[31m日本\x1b[0m\n\tx\n");
+        assert_eq!((rows, columns), (2, 9));
+        let placed: Vec<(usize, usize, usize, &str)> =
+            cells.iter().map(|c| (c.row, c.column, c.width, c.text.as_str())).collect();
+        assert_eq!(placed, [(0, 0, 2, "ab"), (0, 2, 4, "日本"), (1, 0, 9, "        x")]);
+        assert_eq!(cells[1].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_svg_output() {
+        let options = SvgOptions::default().window_chrome(false).font("mono", 10.0);
+        let svg = to_svg("\x1b[1;7;32m<ok>\x1b[0m", &options);
+        assert_eq!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"56\" height=\"46\" viewBox=\"0 0 56 46\" font-family=\"mono\" font-size=\"10\">\n\
+             <rect width=\"56\" height=\"46\" rx=\"8\" fill=\"#000000\"/>\n\
+             <rect x=\"16\" y=\"16\" width=\"24\" height=\"14\" fill=\"#00cd00\"/>\n\
+             <text x=\"16\" y=\"26.5\" fill=\"#000000\" textLength=\"24\" lengthAdjust=\"spacingAndGlyphs\" xml:space=\"preserve\" font-weight=\"bold\">&lt;ok&gt;</text>\n\
+             </svg>\n"
+        );
+        let themed = to_svg("\x1b[31mx", &SvgOptions::default().theme(TerminalTheme::SOLARIZED_DARK).title("a&b"));
+        assert!(themed.contains("fill=\"#dc322f\" textLength=\"8.4\""));
+        assert!(themed.contains(">a&amp;b</text>"));
+    }
+}
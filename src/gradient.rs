@@ -0,0 +1,242 @@
+// File: src\gradient.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Multi-stop color gradients over text.
+// License: MIT
+
+//! Gradient text.
+//!
+//! [`gradient`] colors every grapheme of a text with a color taken from a
+//! multi-stop gradient. Positions are measured in terminal columns, so wide
+//! characters such as CJK or emoji take up as much of the gradient as they
+//! do of the screen, and multi-line text gets aligned columns. Styles already
+//! present in the text (bold, a background, ...) are kept; only the color
+//! the gradient paints is replaced.
+//!
+//! # Examples
+//! ```
+//! use make_colors::colorspace::ColorSpace;
+//! use make_colors::gradient::{self, Direction, GradientOptions};
+//! use make_colors::Color;
+//!
+//! let stops = [Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)];
+//! println!("{}", gradient::gradient("GRADIENT TEXT", &stops, &GradientOptions::default()));
+//!
+//! let options = GradientOptions::default().space(ColorSpace::Hsl).direction(Direction::Vertical).background();
+//! println!("{}", gradient::gradient(" line one \n line two \n line three ", &stops, &options));
+//!
+//! println!("{}", gradient::rainbow("Taste the rainbow"));
+//! ```
+
+use crate::colorspace::ColorSpace;
+use crate::spans::{self, Span};
+use crate::{width, Color};
+
+/// Red, orange, yellow, green, blue, indigo and violet
+pub const RAINBOW: [Color; 7] = [
+    Color::Rgb(255, 0, 0),
+    Color::Rgb(255, 127, 0),
+    Color::Rgb(255, 255, 0),
+    Color::Rgb(0, 255, 0),
+    Color::Rgb(0, 0, 255),
+    Color::Rgb(75, 0, 130),
+    Color::Rgb(148, 0, 211),
+];
+
+/// Which way the gradient runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// From the first to the last column
+    #[default]
+    Horizontal,
+    /// From the first to the last line
+    Vertical,
+}
+
+/// Which color the gradient paints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Target {
+    #[default]
+    Foreground,
+    Background,
+}
+
+/// Options for [`gradient`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GradientOptions {
+    /// Color space the stops are blended in
+    pub space: ColorSpace,
+    pub direction: Direction,
+    pub target: Target,
+}
+
+impl Default for GradientOptions {
+    fn default() -> Self {
+        GradientOptions {
+            space: ColorSpace::Oklab,
+            direction: Direction::Horizontal,
+            target: Target::Foreground,
+        }
+    }
+}
+
+impl GradientOptions {
+    pub fn space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Paint the background instead of the text
+    pub fn background(mut self) -> Self {
+        self.target = Target::Background;
+        self
+    }
+}
+
+/// Color at position `t` (`0.0..=1.0`) of a gradient through evenly spaced `stops`
+///
+/// Returns `None` when there are no stops.
+///
+/// # Examples
+/// ```
+/// use make_colors::colorspace::ColorSpace;
+/// use make_colors::gradient::sample;
+/// use make_colors::Color;
+///
+/// let stops = [Color::Rgb(0, 0, 0), Color::Rgb(255, 0, 0), Color::Rgb(255, 255, 255)];
+/// assert_eq!(sample(&stops, 0.5, ColorSpace::Rgb), Some(Color::Rgb(255, 0, 0)));
+/// assert_eq!(sample(&stops, 0.75, ColorSpace::Rgb), Some(Color::Rgb(255, 128, 128)));
+/// ```
+pub fn sample(stops: &[Color], t: f32, space: ColorSpace) -> Option<Color> {
+    let segments = stops.len().checked_sub(1)?;
+    if segments == 0 {
+        return Some(stops[0]);
+    }
+
+    let scaled = t.clamp(0.0, 1.0) * segments as f32;
+    let index = (scaled as usize).min(segments - 1);
+    Some(stops[index].mix_in(stops[index + 1], scaled - index as f32, space))
+}
+
+/// Color each grapheme of `text` along a gradient through `stops`
+///
+/// Escape sequences already in `text` are parsed, and the styles they set
+/// are kept apart from the painted color. Non-SGR escapes are dropped. With
+/// no stops the text is returned unchanged; colors are downsampled to the
+/// color support of stdout.
+pub fn gradient(text: &str, stops: &[Color], options: &GradientOptions) -> String {
+    if stops.is_empty() {
+        return text.to_string();
+    }
+
+    let parsed = spans::parse(text);
+    let plain: String = parsed.iter().map(|span| span.text.as_str()).collect();
+    let lines = plain.split('\n').count();
+    let columns = plain.split('\n').map(width::text_width).max().unwrap_or(0);
+
+    let position = |line: usize, column: usize| match options.direction {
+        Direction::Horizontal => column as f32 / columns.saturating_sub(1).max(1) as f32,
+        Direction::Vertical => line as f32 / lines.saturating_sub(1).max(1) as f32,
+    };
+
+    let mut painted: Vec<Span> = Vec::new();
+    let (mut line, mut column) = (0, 0);
+    for span in parsed {
+        for grapheme in width::graphemes(&span.text) {
+            let style = if grapheme.ends_with('\n') {
+                line += 1;
+                column = 0;
+                span.style
+            } else {
+                let color = sample(stops, position(line, column), options.space).unwrap_or_default();
+                column += width::grapheme_width(grapheme);
+                match options.target {
+                    Target::Foreground => span.style.fg(color),
+                    Target::Background => span.style.bg(color),
+                }
+            };
+
+            match painted.last_mut() {
+                Some(last) if last.style == style => last.text.push_str(grapheme),
+                _ => painted.push(Span::new(grapheme, style)),
+            }
+        }
+    }
+
+    spans::render(&painted)
+}
+
+/// [`gradient`] through the [`RAINBOW`] colors, blended in HSL
+pub fn rainbow(text: &str) -> String {
+    gradient(text, &RAINBOW, &GradientOptions::default().space(ColorSpace::Hsl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support;
+    use crate::{Attributes, Style};
+
+    fn rgb() -> GradientOptions {
+        GradientOptions::default().space(ColorSpace::Rgb)
+    }
+
+    #[test]
+    fn test_sample() {
+        let stops = [Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)];
+        assert_eq!(sample(&stops, -1.0, ColorSpace::Rgb), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(sample(&stops, 2.0, ColorSpace::Rgb), Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(sample(&stops, 0.5, ColorSpace::Hsl), Some(Color::Rgb(255, 0, 255)));
+        assert_eq!(sample(&stops[..1], 0.5, ColorSpace::Rgb), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(sample(&[], 0.5, ColorSpace::Rgb), None);
+    }
+
+    #[test]
+    fn test_gradient_columns() {
+        support::force_true_color();
+        let stops = [Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)];
+        let parsed = spans::parse(&gradient("日本b", &stops, &rgb()));
+        let colors: Vec<_> = parsed.iter().map(|span| (span.text.as_str(), span.style.fg)).collect();
+        assert_eq!(
+            colors,
+            [
+                ("日", Some(Color::Rgb(255, 0, 0))),
+                ("本", Some(Color::Rgb(128, 0, 128))),
+                ("b", Some(Color::Rgb(0, 0, 255))),
+            ]
+        );
+
+        // Lines share columns, and line breaks are left unstyled
+        let parsed = spans::parse(&gradient("ab\nabc", &stops, &rgb()));
+        assert_eq!(parsed[1], Span::new("b", Color::Rgb(128, 0, 128).into()));
+        assert_eq!(parsed[2], Span::new("\n", Style::new()));
+        assert_eq!(parsed[4].style.fg, Some(Color::Rgb(128, 0, 128)));
+        assert_eq!(gradient("ab", &[], &rgb()), "ab");
+    }
+
+    #[test]
+    fn test_gradient_modes() {
+        support::force_true_color();
+        let stops = [Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)];
+        let text = "\x1b[1;31mab\x1b[0m\ncd";
+        let options = rgb().direction(Direction::Vertical).background();
+        let parsed = spans::parse(&gradient(text, &stops, &options));
+        assert_eq!(
+            parsed,
+            [
+                Span::new("ab", Style::new().fg(Color::Red).bg(Color::Rgb(0, 0, 0)).attrs(Attributes::BOLD)),
+                Span::new("\n", Style::new()),
+                Span::new("cd", Style::new().bg(Color::Rgb(255, 255, 255))),
+            ]
+        );
+
+        let rainbow = spans::parse(&rainbow("abcdefg"));
+        let colors: Vec<_> = rainbow.iter().filter_map(|span| span.style.fg).collect();
+        assert_eq!(colors, RAINBOW);
+    }
+}
@@ -0,0 +1,503 @@
+// File: src\highlight.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Rule-based highlighting of literals, keywords and patterns in text.
+// License: MIT
+
+//! Pattern highlighting.
+//!
+//! A [`Highlighter`] holds a list of [`Rule`]s, each pairing a [`Pattern`]
+//! with a [`Style`], and styles every match in a line of text. Patterns are
+//! literals, whole-word keywords, matcher functions or, with the `regex`
+//! feature, regular expressions. When matches overlap, the rule with the
+//! higher priority wins; between equal priorities the earlier and then the
+//! longer match wins, then the rule added first.
+//!
+//! [`Highlighter::repr`] is a ready-made highlighter for log lines and
+//! debug output, in the spirit of Python rich's default one: numbers,
+//! quoted strings, booleans, `None`/`null`, UUIDs, IP addresses, URLs,
+//! `key=` names and brackets.
+//!
+//! # Examples
+//! ```
+//! use make_colors::highlight::Highlighter;
+//! use make_colors::{Color, Style};
+//!
+//! let highlighter = Highlighter::new()
+//!     .keywords(&["ERROR", "FATAL"], "bold red".parse().unwrap())
+//!     .literal("->", Style::new().fg(Color::BrightBlack));
+//! println!("{}", highlighter.highlight("ERROR job 7 -> retry"));
+//!
+//! let line = Highlighter::repr().highlight("GET /api user=42 from 10.0.0.7 ok=True");
+//! println!("{}", line);
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::Range;
+
+#[cfg(feature = "regex")]
+use crate::MakeColorsError;
+use crate::{Attributes, Color, Style, StyledString};
+
+/// Finds the byte ranges to style in a text
+pub type MatchFn = fn(&str) -> Vec<Range<usize>>;
+
+/// What a [`Rule`] looks for
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Every occurrence of the text
+    Literal(String),
+    /// The text as a whole word, not inside a longer identifier
+    Keyword(String),
+    /// A regular expression
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+    /// Byte ranges returned by a function
+    Function(MatchFn),
+}
+
+impl Pattern {
+    /// Byte ranges of all matches in `text`
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            Pattern::Literal(literal) if literal.is_empty() => Vec::new(),
+            Pattern::Literal(literal) => text
+                .match_indices(literal.as_str())
+                .map(|(start, m)| start..start + m.len())
+                .collect(),
+            Pattern::Keyword(word) if word.is_empty() => Vec::new(),
+            Pattern::Keyword(word) => text
+                .match_indices(word.as_str())
+                .map(|(start, m)| start..start + m.len())
+                .filter(|range| at_word_boundary(text, range))
+                .collect(),
+            #[cfg(feature = "regex")]
+            Pattern::Regex(regex) => regex.find_iter(text).map(|m| m.range()).collect(),
+            Pattern::Function(find) => find(text),
+        }
+    }
+}
+
+/// A pattern, the style of its matches and their priority
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub pattern: Pattern,
+    pub style: Style,
+    /// Overlapping matches of higher priority rules win
+    pub priority: i32,
+}
+
+impl Rule {
+    pub fn new(pattern: Pattern, style: Style) -> Self {
+        Rule {
+            pattern,
+            style,
+            priority: 0,
+        }
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Styles matches of a list of rules
+#[derive(Debug, Clone, Default)]
+pub struct Highlighter {
+    rules: Vec<Rule>,
+}
+
+impl Highlighter {
+    pub fn new() -> Self {
+        Highlighter { rules: Vec::new() }
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Style every occurrence of `text`
+    pub fn literal(self, text: &str, style: Style) -> Self {
+        self.rule(Rule::new(Pattern::Literal(text.to_string()), style))
+    }
+
+    /// Style `word` where it appears as a whole word
+    pub fn keyword(self, word: &str, style: Style) -> Self {
+        self.rule(Rule::new(Pattern::Keyword(word.to_string()), style))
+    }
+
+    /// Style each of `words` where it appears as a whole word
+    pub fn keywords(self, words: &[&str], style: Style) -> Self {
+        words.iter().fold(self, |highlighter, word| highlighter.keyword(word, style))
+    }
+
+    /// Style the ranges found by `find`
+    pub fn function(self, find: MatchFn, style: Style) -> Self {
+        self.rule(Rule::new(Pattern::Function(find), style))
+    }
+
+    /// Style matches of a regular expression
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::highlight::Highlighter;
+    /// use make_colors::{Color, Style};
+    ///
+    /// let highlighter = Highlighter::new().regex(r"#\d+", Style::new().fg(Color::Magenta)).unwrap();
+    /// assert_eq!(highlighter.matches("fixes #12 and #7").len(), 2);
+    /// assert!(Highlighter::new().regex("(", Style::new()).is_err());
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(self, pattern: &str, style: Style) -> Result<Self, MakeColorsError> {
+        let regex = regex::Regex::new(pattern).map_err(|err| MakeColorsError::InvalidPattern {
+            pattern: pattern.to_string(),
+            message: err.to_string(),
+        })?;
+        Ok(self.rule(Rule::new(Pattern::Regex(regex), style)))
+    }
+
+    /// Highlighter for numbers, strings, booleans, `None`/`null`, UUIDs, IP
+    /// addresses, URLs, `key=` names and brackets
+    pub fn repr() -> Self {
+        let styled = |color: Color, attrs: Attributes| Style::new().fg(color).attrs(attrs);
+        Highlighter::new()
+            .rule(Rule::new(Pattern::Function(find_strings), Color::Green.into()).priority(50))
+            .rule(Rule::new(Pattern::Function(find_urls), styled(Color::BrightBlue, Attributes::UNDERLINE)).priority(40))
+            .rule(Rule::new(Pattern::Function(find_uuids), Color::BrightYellow.into()).priority(30))
+            .rule(Rule::new(Pattern::Function(find_ip_addresses), styled(Color::BrightGreen, Attributes::BOLD)).priority(30))
+            .keywords(&["True", "true"], styled(Color::BrightGreen, Attributes::ITALIC))
+            .keywords(&["False", "false"], styled(Color::BrightRed, Attributes::ITALIC))
+            .keywords(&["None", "null", "nil"], styled(Color::Magenta, Attributes::ITALIC))
+            .function(find_numbers, styled(Color::Cyan, Attributes::BOLD))
+            .function(find_attribute_names, Color::Yellow.into())
+            .function(find_brackets, Style::new().attrs(Attributes::BOLD))
+    }
+
+    /// Matches that win over the ones they overlap, in text order
+    pub fn matches(&self, text: &str) -> Vec<(Range<usize>, Style)> {
+        let mut candidates: Vec<(usize, &Rule, Range<usize>)> = self
+            .rules
+            .iter()
+            .enumerate()
+            .flat_map(|(index, rule)| rule.pattern.find(text).into_iter().map(move |range| (index, rule, range)))
+            .filter(|(_, _, range)| !range.is_empty() && range.end <= text.len())
+            .collect();
+        candidates.sort_by(|(a_index, a, a_range), (b_index, b, b_range)| {
+            b.priority
+                .cmp(&a.priority)
+                .then(a_range.start.cmp(&b_range.start))
+                .then(b_range.len().cmp(&a_range.len()))
+                .then(a_index.cmp(b_index))
+        });
+
+        let mut accepted: Vec<(Range<usize>, Style)> = Vec::new();
+        for (_, rule, range) in candidates {
+            if accepted.iter().all(|(taken, _)| range.end <= taken.start || range.start >= taken.end) {
+                accepted.push((range, rule.style));
+            }
+        }
+        accepted.sort_by_key(|(range, _)| range.start);
+        accepted
+    }
+
+    /// Highlight a line of text
+    ///
+    /// Escape codes already in `text` are parsed, and their styles are kept
+    /// under the highlighting.
+    pub fn highlight(&self, text: &str) -> StyledString {
+        self.highlight_styled(&StyledString::from_ansi(text))
+    }
+
+    /// Highlight styled text, layering match styles over the existing ones
+    pub fn highlight_styled(&self, text: &StyledString) -> StyledString {
+        let matches = self.matches(&text.text());
+        let mut result = StyledString::new();
+        let mut offset = 0;
+
+        for span in text.spans() {
+            let end = offset + span.text.len();
+            let mut position = offset;
+            while position < end {
+                let (style, next) = match matches.iter().find(|(range, _)| range.end > position) {
+                    Some((range, style)) if range.start <= position => (span.style.patch(*style), range.end.min(end)),
+                    Some((range, _)) => (span.style, range.start.min(end)),
+                    None => (span.style, end),
+                };
+                result.push_styled(&span.text[position - offset..next - offset], style);
+                position = next;
+            }
+            offset = end;
+        }
+
+        result
+    }
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80
+}
+
+/// Whether `range` is neither preceded nor followed by a word character
+fn at_word_boundary(text: &str, range: &Range<usize>) -> bool {
+    let bytes = text.as_bytes();
+    let before = range.start == 0 || !is_word_byte(bytes[range.start - 1]);
+    let after = range.end == bytes.len() || !is_word_byte(bytes[range.end]);
+    before && after
+}
+
+/// Maximal runs of bytes accepted by `accept`, as ranges
+fn runs(text: &str, accept: impl Fn(u8) -> bool) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (i, &byte) in text.as_bytes().iter().enumerate() {
+        match (accept(byte), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        runs.push(s..text.len());
+    }
+    runs
+}
+
+/// Integers, decimals, exponents and `0x` hex numbers
+fn find_numbers(text: &str) -> Vec<Range<usize>> {
+    runs(text, |b| b.is_ascii_alphanumeric() || b == b'.' || b == b'_' || b == b'-' || b == b'+')
+        .into_iter()
+        .flat_map(|run| {
+            // Split on '-' and '+' not belonging to an exponent, so ranges like 3-5 give two numbers
+            let mut pieces = Vec::new();
+            let mut start = run.start;
+            let bytes = text.as_bytes();
+            for i in run.clone() {
+                let sign = bytes[i] == b'-' || bytes[i] == b'+';
+                let exponent = i > run.start && matches!(bytes[i - 1], b'e' | b'E') && i > start + 1;
+                if sign && !exponent && i > start {
+                    pieces.push(start..i);
+                    start = i;
+                }
+            }
+            pieces.push(start..run.end);
+            pieces
+        })
+        .filter_map(|range| {
+            let token = &text[range.clone()];
+            let unsigned = token.strip_prefix(['-', '+']).unwrap_or(token);
+            let offset = token.len() - unsigned.len();
+            // A sign only counts at the start of a word
+            let sign_ok = offset == 0 || range.start == 0 || !is_word_byte(text.as_bytes()[range.start - 1]);
+            let valid = match unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+                Some(hex) => !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit() || b == b'_'),
+                None => is_decimal(unsigned),
+            };
+            let start = if sign_ok { range.start } else { range.start + offset };
+            valid.then_some(start..range.end)
+        })
+        .collect()
+}
+
+/// `1`, `1_000`, `3.14`, `.5`, `6.02e23`, `1e-9`
+fn is_decimal(token: &str) -> bool {
+    let (mantissa, exponent) = match token.find(['e', 'E']) {
+        Some(i) => (&token[..i], Some(&token[i + 1..])),
+        None => (token, None),
+    };
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit() || b == b'_') && !s.starts_with('_');
+    let mantissa_ok = match mantissa.split_once('.') {
+        Some((whole, fraction)) => (whole.is_empty() || digits(whole)) && digits(fraction),
+        None => digits(mantissa),
+    };
+    let exponent_ok = exponent.is_none_or(|e| digits(e.strip_prefix(['-', '+']).unwrap_or(e)));
+    mantissa_ok && exponent_ok
+}
+
+/// Single- or double-quoted strings on one line, with backslash escapes
+fn find_strings(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut strings = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let quote = bytes[i];
+        // An apostrophe inside a word (don't) is not a quote
+        if quote == b'"' || (quote == b'\'' && (i == 0 || !is_word_byte(bytes[i - 1]))) {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != quote && bytes[j] != b'\n' {
+                j += if bytes[j] == b'\\' { 2 } else { 1 };
+            }
+            if j < bytes.len() && bytes[j] == quote {
+                strings.push(i..j + 1);
+                i = j + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    strings
+}
+
+/// `8-4-4-4-12` hexadecimal UUIDs
+fn find_uuids(text: &str) -> Vec<Range<usize>> {
+    runs(text, |b| b.is_ascii_alphanumeric() || b == b'-')
+        .into_iter()
+        .filter(|range| {
+            let groups: Vec<&str> = text[range.clone()].split('-').collect();
+            groups.iter().map(|g| g.len()).eq([8, 4, 4, 4, 12])
+                && groups.iter().all(|g| g.bytes().all(|b| b.is_ascii_hexdigit()))
+        })
+        .collect()
+}
+
+/// IPv4 and IPv6 addresses, with an optional `:port` after IPv4
+fn find_ip_addresses(text: &str) -> Vec<Range<usize>> {
+    runs(text, |b| b.is_ascii_hexdigit() || b == b'.' || b == b':')
+        .into_iter()
+        .filter(|range| at_word_boundary(text, range))
+        .filter_map(|range| {
+            let candidate = text[range.clone()].trim_end_matches(['.', ':']);
+            let end = range.start + candidate.len();
+            if candidate.contains(':') && candidate.parse::<Ipv6Addr>().is_ok() {
+                return Some(range.start..end);
+            }
+            let host = candidate.split_once(':').map_or(candidate, |(host, _)| host);
+            host.parse::<Ipv4Addr>().is_ok().then(|| range.start..range.start + host.len())
+        })
+        .collect()
+}
+
+/// `http`, `https`, `ftp` and `file` URLs
+fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = Vec::new();
+    for scheme in ["https://", "http://", "ftp://", "file://"] {
+        for (start, _) in text.match_indices(scheme) {
+            if start > 0 && is_word_byte(text.as_bytes()[start - 1]) {
+                continue;
+            }
+            let rest = &text[start..];
+            let length = rest.find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>')).unwrap_or(rest.len());
+            let url = rest[..length].trim_end_matches(['.', ',', ';', ':', '!', '?', ')']);
+            if url.len() > scheme.len() {
+                urls.push(start..start + url.len());
+            }
+        }
+    }
+    urls
+}
+
+/// Identifiers directly followed by `=`, as in `key=value`
+fn find_attribute_names(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    runs(text, |b| b.is_ascii_alphanumeric() || b == b'_')
+        .into_iter()
+        .filter(|range| {
+            !bytes[range.start].is_ascii_digit()
+                && bytes.get(range.end) == Some(&b'=')
+                && bytes.get(range.end + 1) != Some(&b'=')
+        })
+        .collect()
+}
+
+fn find_brackets(text: &str) -> Vec<Range<usize>> {
+    text.match_indices(['(', ')', '[', ']', '{', '}']).map(|(i, _)| i..i + 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(find: MatchFn, text: &str) -> Vec<&str> {
+        find(text).into_iter().map(|range| &text[range]).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn test_matchers() {
+        assert_eq!(found(find_numbers, "x=-1.5e3, 0xFF 42px v2 3-5 1_000 .5 1.2.3"), ["-1.5e3", "0xFF", "3", "5", "1_000", ".5"]);
+        assert_eq!(found(find_strings, r#"say "hi \"there\"" and 'x' don't "open"#), [r#""hi \"there\"""#, "'x'"]);
+        assert_eq!(
+            found(find_uuids, "id 123e4567-e89b-12d3-a456-426614174000 not 123e4567-e89b-12d3-a456"),
+            ["123e4567-e89b-12d3-a456-426614174000"]
+        );
+        assert_eq!(
+            found(find_ip_addresses, "from 10.0.0.7:8080, ::1 and fe80::1ff:fe23:4567:890a. not 999.1.1.1 or 12:30:45"),
+            ["10.0.0.7", "::1", "fe80::1ff:fe23:4567:890a"]
+        );
+        assert_eq!(found(find_urls, "see (https://example.com/a?b=1), xhttp://no"), ["https://example.com/a?b=1"]);
+        assert_eq!(found(find_attribute_names, "a=1 b==2 _c= 9d=1"), ["a", "_c"]);
+    }
+
+    #[test]
+    fn test_priorities() {
+        let red = Style::new().fg(Color::Red);
+        let blue = Style::new().fg(Color::Blue);
+        let highlighter = Highlighter::new()
+            .literal("error", red)
+            .keyword("err", blue)
+            .rule(Rule::new(Pattern::Literal("or c".to_string()), blue).priority(1));
+
+        let matches = highlighter.matches("err errors error code");
+        let found: Vec<_> = matches.iter().map(|(range, style)| (range.clone(), style.fg)).collect();
+        assert_eq!(found, [(0..3, Some(Color::Blue)), (4..9, Some(Color::Red)), (14..18, Some(Color::Blue))]);
+
+        let repr = |text: &str| -> Vec<_> {
+            Highlighter::repr().matches(text).into_iter().map(|(range, style)| (range, style.fg)).collect()
+        };
+        // A quoted URL is a string, and an IP address inside a URL is part of the URL
+        assert_eq!(
+            repr(r#"url="http://10.0.0.1/" id=7"#),
+            [(0..3, Some(Color::Yellow)), (4..22, Some(Color::Green)), (23..25, Some(Color::Yellow)), (26..27, Some(Color::Cyan))]
+        );
+        assert_eq!(
+            repr(r#"url=http://10.0.0.1:8080/a "x" ok=True None"#),
+            [
+                (0..3, Some(Color::Yellow)),
+                (4..26, Some(Color::BrightBlue)),
+                (27..30, Some(Color::Green)),
+                (31..33, Some(Color::Yellow)),
+                (34..38, Some(Color::BrightGreen)),
+                (39..43, Some(Color::Magenta)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_styled() {
+        let text = format!("{}warn{} count=3", "\x1b[1m", "\x1b[0m");
+        let highlighted = Highlighter::new().literal("rn c", Style::new().fg(Color::Red)).highlight(&text);
+        let styles: Vec<_> = highlighted.spans().iter().map(|span| (span.text.as_str(), span.style.to_string())).collect();
+        assert_eq!(
+            styles,
+            [
+                ("wa", "bold".to_string()),
+                ("rn", "bold red".to_string()),
+                (" c", "red".to_string()),
+                ("ount=3", "none".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_rules() {
+        let highlighter = Highlighter::new()
+            .regex(r"\b[A-Z]{3,}\b", Style::new().attrs(Attributes::BOLD))
+            .unwrap()
+            .keyword("WARN", Style::new().fg(Color::Yellow));
+        let matches = highlighter.matches("WARN: DISK at 91%");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0], (0..4, Style::new().attrs(Attributes::BOLD)));
+        assert!(matches!(
+            Highlighter::new().regex("[", Style::new()),
+            Err(MakeColorsError::InvalidPattern { .. })
+        ));
+    }
+}
@@ -16,6 +16,10 @@
 //! - 📝 Simple and intuitive API
 //! - 🖋 Attributes support (bold, underline, italic, etc.)
 //! - 🔧 Flexible formatting with multiple notations
+//! - 🏷 Rich-style inline markup (`[bold red on white]text[/]`)
+//! - 🧩 `Style` values and allocation-free `Styled<T>` formatting
+//! - 🔗 `Colorize` extension methods (`"text".red().bold()`)
+//! - 🖥 Automatic color support detection (`NO_COLOR`, `FORCE_COLOR`, TTY, ...)
 //!
 //! ## Quick Start
 //!
@@ -29,7 +33,7 @@
 //! println!("{}", make_colors("Important", "white", Some("red")));
 //!
 //! // Using hex colors
-//! println!("{}", make_colors_hex("Cyan text", "#00FFFF", None));
+//! println!("{}", make_colors_hex("Cyan text", "#00FFFF", None).unwrap());
 //!
 //! // Using RGB
 //! println!("{}", make_colors_rgb("Custom color", (255, 100, 50), None));
@@ -37,72 +41,96 @@
 
 use std::fmt;
 
-/// ANSI color codes for standard colors
-pub mod ansi {
-    // Foreground colors
-    pub const BLACK: &str = "\x1b[30m";
-    pub const RED: &str = "\x1b[31m";
-    pub const GREEN: &str = "\x1b[32m";
-    pub const YELLOW: &str = "\x1b[33m";
-    pub const BLUE: &str = "\x1b[34m";
-    pub const MAGENTA: &str = "\x1b[35m";
-    pub const CYAN: &str = "\x1b[36m";
-    pub const WHITE: &str = "\x1b[37m";
-    
-    // Light colors
-    pub const LIGHT_BLACK: &str = "\x1b[90m";
-    pub const LIGHT_RED: &str = "\x1b[91m";
-    pub const LIGHT_GREEN: &str = "\x1b[92m";
-    pub const LIGHT_YELLOW: &str = "\x1b[93m";
-    pub const LIGHT_BLUE: &str = "\x1b[94m";
-    pub const LIGHT_MAGENTA: &str = "\x1b[95m";
-    pub const LIGHT_CYAN: &str = "\x1b[96m";
-    pub const LIGHT_WHITE: &str = "\x1b[97m";
-    
-    // Background colors
-    pub const BG_BLACK: &str = "\x1b[40m";
-    pub const BG_RED: &str = "\x1b[41m";
-    pub const BG_GREEN: &str = "\x1b[42m";
-    pub const BG_YELLOW: &str = "\x1b[43m";
-    pub const BG_BLUE: &str = "\x1b[44m";
-    pub const BG_MAGENTA: &str = "\x1b[45m";
-    pub const BG_CYAN: &str = "\x1b[46m";
-    pub const BG_WHITE: &str = "\x1b[47m";
-    
-    // Light background colors
-    pub const BG_LIGHT_BLACK: &str = "\x1b[100m";
-    pub const BG_LIGHT_RED: &str = "\x1b[101m";
-    pub const BG_LIGHT_GREEN: &str = "\x1b[102m";
-    pub const BG_LIGHT_YELLOW: &str = "\x1b[103m";
-    pub const BG_LIGHT_BLUE: &str = "\x1b[104m";
-    pub const BG_LIGHT_MAGENTA: &str = "\x1b[105m";
-    pub const BG_LIGHT_CYAN: &str = "\x1b[106m";
-    pub const BG_LIGHT_WHITE: &str = "\x1b[107m";
-    
-    // Reset and attributes
-    pub const RESET: &str = "\x1b[0m";
-    pub const BOLD: &str = "\x1b[1m";
-    pub const DIM: &str = "\x1b[2m";
-    pub const ITALIC: &str = "\x1b[3m";
-    pub const UNDERLINE: &str = "\x1b[4m";
-    pub const BLINK: &str = "\x1b[5m";
-    pub const REVERSE: &str = "\x1b[7m";
-    pub const HIDDEN: &str = "\x1b[8m";
-    pub const STRIKETHROUGH: &str = "\x1b[9m";
-}
+pub mod ansi;
+pub mod attributes;
+pub mod color;
+pub mod colorize;
+pub mod colorspace;
+pub mod contrast;
+pub mod export;
+pub mod gradient;
+pub mod highlight;
+pub mod markup;
+pub mod names;
+pub mod pretty;
+pub mod quantize;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod spans;
+pub mod style;
+pub mod styled_string;
+pub mod support;
+#[cfg(feature = "syntax")]
+pub mod syntax;
+pub mod theme;
+pub mod vision;
+mod width;
+
+pub use attributes::Attributes;
+pub use color::{parse_color, Color};
+pub use colorize::Colorize;
+pub use gradient::gradient;
+pub use quantize::{ansi256_to_rgb, rgb_to_ansi256};
+pub use spans::Span;
+pub use style::{Style, Styled};
+pub use styled_string::StyledString;
+pub use support::{ColorLevel, Stream};
+pub use theme::Theme;
 
 /// Errors that can occur when using make_colors
 #[derive(Debug, Clone)]
 pub enum MakeColorsError {
     InvalidHexColor(String),
+    InvalidHexLength(String),
+    InvalidHexDigit { input: String, digit: char },
+    InvalidColorFunction(String),
+    InvalidColorArguments { function: String, expected: usize, found: usize },
+    InvalidColorComponent { function: String, component: &'static str, value: String },
     InvalidColorName(String),
+    InvalidAttribute(String),
+    InvalidStyle(String),
+    InvalidMarkup { position: usize, message: String },
+    InvalidTheme { line: usize, column: usize, message: String },
+    ThemeFile { path: String, message: String },
+    InvalidPattern { pattern: String, message: String },
+    InvalidLanguage(String),
 }
 
 impl fmt::Display for MakeColorsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             MakeColorsError::InvalidHexColor(hex) => write!(f, "Invalid hex color: {}", hex),
+            MakeColorsError::InvalidHexLength(hex) => {
+                write!(f, "Invalid hex color length: {} (expected 3, 4, 6 or 8 digits)", hex)
+            }
+            MakeColorsError::InvalidHexDigit { input, digit } => {
+                write!(f, "Invalid hex digit '{}' in {}", digit, input)
+            }
+            MakeColorsError::InvalidColorFunction(input) => {
+                write!(f, "Unknown or malformed color function: {}", input)
+            }
+            MakeColorsError::InvalidColorArguments { function, expected, found } => {
+                write!(f, "{}() expects {} arguments, found {}", function, expected, found)
+            }
+            MakeColorsError::InvalidColorComponent { function, component, value } => {
+                write!(f, "Invalid {} '{}' in {}()", component, value, function)
+            }
             MakeColorsError::InvalidColorName(name) => write!(f, "Invalid color name: {}", name),
+            MakeColorsError::InvalidAttribute(name) => write!(f, "Invalid attribute: {}", name),
+            MakeColorsError::InvalidStyle(message) => write!(f, "Invalid style: {}", message),
+            MakeColorsError::InvalidMarkup { position, message } => {
+                write!(f, "Invalid markup at position {}: {}", position, message)
+            }
+            MakeColorsError::InvalidTheme { line, column, message } => {
+                write!(f, "Invalid theme at line {}, column {}: {}", line, column, message)
+            }
+            MakeColorsError::ThemeFile { path, message } => {
+                write!(f, "Cannot load theme file {}: {}", path, message)
+            }
+            MakeColorsError::InvalidPattern { pattern, message } => {
+                write!(f, "Invalid pattern {}: {}", pattern, message)
+            }
+            MakeColorsError::InvalidLanguage(name) => write!(f, "Unknown language: {}", name),
         }
     }
 }
@@ -111,121 +139,31 @@ impl std::error::Error for MakeColorsError {}
 
 /// Convert hex color string to RGB tuple
 /// 
+/// Accepts 3, 4, 6 or 8 digits with or without `#`; alpha digits are ignored.
+/// 
 /// # Examples
 /// ```
 /// use make_colors::hex_to_rgb;
 /// 
 /// let (r, g, b) = hex_to_rgb("#00FFFF").unwrap();
 /// assert_eq!((r, g, b), (0, 255, 255));
+/// assert_eq!(hex_to_rgb("#0ff").unwrap(), (0, 255, 255));
 /// ```
 pub fn hex_to_rgb(hex: &str) -> Result<(u8, u8, u8), MakeColorsError> {
-    let hex = hex.trim_start_matches('#');
-    
-    if hex.len() != 6 {
-        return Err(MakeColorsError::InvalidHexColor(hex.to_string()));
-    }
-    
-    let r = u8::from_str_radix(&hex[0..2], 16)
-        .map_err(|_| MakeColorsError::InvalidHexColor(hex.to_string()))?;
-    let g = u8::from_str_radix(&hex[2..4], 16)
-        .map_err(|_| MakeColorsError::InvalidHexColor(hex.to_string()))?;
-    let b = u8::from_str_radix(&hex[4..6], 16)
-        .map_err(|_| MakeColorsError::InvalidHexColor(hex.to_string()))?;
-    
-    Ok((r, g, b))
-}
-
-/// Map color name or abbreviation to ANSI code
-fn get_color_code(color: &str, background: bool) -> Option<String> {
-    let color = color.to_lowercase();
-    let color = color.trim();
-    
-    // Handle "light" prefix
-    let is_light = color.starts_with("light");
-    let base_color = if is_light {
-        &color[5..]
-    } else {
-        &color
-    };
-    
-    // Map abbreviations and full names
-    let normalized = match base_color {
-        "b" | "bk" | "black" => "black",
-        "r" | "rd" | "re" | "red" => "red",
-        "g" | "gr" | "ge" | "green" => "green",
-        "y" | "ye" | "yl" | "yellow" => "yellow",
-        "bl" | "blue" => "blue",
-        "m" | "mg" | "ma" | "magenta" => "magenta",
-        "c" | "cy" | "cn" | "cyan" => "cyan",
-        "w" | "wh" | "wi" | "wt" | "white" => "white",
-        _ => return None,
-    };
-    
-    // Get the appropriate ANSI code
-    let code = match (normalized, is_light, background) {
-        ("black", false, false) => ansi::BLACK,
-        ("red", false, false) => ansi::RED,
-        ("green", false, false) => ansi::GREEN,
-        ("yellow", false, false) => ansi::YELLOW,
-        ("blue", false, false) => ansi::BLUE,
-        ("magenta", false, false) => ansi::MAGENTA,
-        ("cyan", false, false) => ansi::CYAN,
-        ("white", false, false) => ansi::WHITE,
-        
-        ("black", true, false) => ansi::LIGHT_BLACK,
-        ("red", true, false) => ansi::LIGHT_RED,
-        ("green", true, false) => ansi::LIGHT_GREEN,
-        ("yellow", true, false) => ansi::LIGHT_YELLOW,
-        ("blue", true, false) => ansi::LIGHT_BLUE,
-        ("magenta", true, false) => ansi::LIGHT_MAGENTA,
-        ("cyan", true, false) => ansi::LIGHT_CYAN,
-        ("white", true, false) => ansi::LIGHT_WHITE,
-        
-        ("black", false, true) => ansi::BG_BLACK,
-        ("red", false, true) => ansi::BG_RED,
-        ("green", false, true) => ansi::BG_GREEN,
-        ("yellow", false, true) => ansi::BG_YELLOW,
-        ("blue", false, true) => ansi::BG_BLUE,
-        ("magenta", false, true) => ansi::BG_MAGENTA,
-        ("cyan", false, true) => ansi::BG_CYAN,
-        ("white", false, true) => ansi::BG_WHITE,
-        
-        ("black", true, true) => ansi::BG_LIGHT_BLACK,
-        ("red", true, true) => ansi::BG_LIGHT_RED,
-        ("green", true, true) => ansi::BG_LIGHT_GREEN,
-        ("yellow", true, true) => ansi::BG_LIGHT_YELLOW,
-        ("blue", true, true) => ansi::BG_LIGHT_BLUE,
-        ("magenta", true, true) => ansi::BG_LIGHT_MAGENTA,
-        ("cyan", true, true) => ansi::BG_LIGHT_CYAN,
-        ("white", true, true) => ansi::BG_LIGHT_WHITE,
-        
-        // Catch-all for unknown colors
-        _ => return None,
-    };
-    
-    Some(code.to_string())
+    let digits = hex.trim_start_matches('#');
+    color::parse_hex(digits, hex).map_err(|_| MakeColorsError::InvalidHexColor(digits.to_string()))
 }
 
-/// Get attribute ANSI code
-fn get_attribute_code(attr: &str) -> Option<&'static str> {
-    match attr.to_lowercase().as_str() {
-        "bold" => Some(ansi::BOLD),
-        "dim" => Some(ansi::DIM),
-        "italic" => Some(ansi::ITALIC),
-        "underline" => Some(ansi::UNDERLINE),
-        "blink" => Some(ansi::BLINK),
-        "reverse" => Some(ansi::REVERSE),
-        "hidden" => Some(ansi::HIDDEN),
-        "strikethrough" => Some(ansi::STRIKETHROUGH),
-        _ => None,
-    }
+/// Parse attribute names, skipping unknown ones
+fn get_attributes(attrs: &[&str]) -> Attributes {
+    attrs.iter().filter_map(|attr| Attributes::from_name(attr)).collect()
 }
 
 /// Main function to colorize text with named colors
 /// 
 /// # Examples
 /// ```
-/// use make_colors::make_colors;
+/// use make_colors::{make_colors, make_colors_with_attrs};
 /// 
 /// // Simple usage
 /// let colored = make_colors("Hello", "red", None);
@@ -245,46 +183,81 @@ pub fn make_colors(text: &str, fg: &str, bg: Option<&str>) -> String {
 
 /// Colorize text with attributes
 pub fn make_colors_with_attrs(text: &str, fg: &str, bg: Option<&str>, attrs: &[&str]) -> String {
-    let mut result = String::new();
-    
-    // Add attributes
-    for attr in attrs {
-        if let Some(code) = get_attribute_code(attr) {
-            result.push_str(code);
-        }
-    }
-    
-    // Add foreground color
-    if let Some(fg_code) = get_color_code(fg, false) {
-        result.push_str(&fg_code);
-    }
-    
-    // Add background color
-    if let Some(bg_color) = bg {
-        if let Some(bg_code) = get_color_code(bg_color, true) {
-            result.push_str(&bg_code);
-        }
-    }
-    
-    // Add text and reset
-    result.push_str(text);
-    result.push_str(ansi::RESET);
-    
-    result
+    let fg = fg.parse().ok();
+    let bg = bg.and_then(|bg| bg.parse().ok());
+    paint(text, fg, bg, get_attributes(attrs), support::color_level(Stream::Stdout))
+}
+
+/// Colorize text with named colors, reporting unknown names instead of ignoring them
+///
+/// # Examples
+/// ```
+/// use make_colors::{try_make_colors, MakeColorsError};
+///
+/// assert!(try_make_colors("Hello", "red", Some("white")).is_ok());
+/// assert!(matches!(
+///     try_make_colors("Hello", "redd", None),
+///     Err(MakeColorsError::InvalidColorName(_))
+/// ));
+/// ```
+pub fn try_make_colors(text: &str, fg: &str, bg: Option<&str>) -> Result<String, MakeColorsError> {
+    let fg = fg.parse()?;
+    let bg = bg.map(str::parse).transpose()?;
+    Ok(make_colors_typed(text, fg, bg))
+}
+
+/// Colorize text with typed colors
+///
+/// # Examples
+/// ```
+/// use make_colors::{make_colors_typed, Color};
+///
+/// let colored = make_colors_typed("Error", Color::White, Some(Color::Red));
+/// println!("{}", colored);
+/// ```
+pub fn make_colors_typed(text: &str, fg: Color, bg: Option<Color>) -> String {
+    make_colors_typed_with_attrs(text, fg, bg, Attributes::empty())
+}
+
+/// Colorize text with typed colors and attributes
+///
+/// # Examples
+/// ```
+/// use make_colors::{make_colors_typed_with_attrs, Attributes, Color};
+///
+/// let colored = make_colors_typed_with_attrs(
+///     "Warning",
+///     Color::Yellow,
+///     None,
+///     Attributes::BOLD | Attributes::OVERLINE,
+/// );
+/// println!("{}", colored);
+/// ```
+pub fn make_colors_typed_with_attrs(text: &str, fg: Color, bg: Option<Color>, attrs: Attributes) -> String {
+    paint(text, Some(fg), bg, attrs, support::color_level(Stream::Stdout))
+}
+
+/// Apply attributes, colors and a trailing reset to `text`, downsampling
+/// colors to `level` or emitting nothing if it has no color support
+fn paint(text: &str, fg: Option<Color>, bg: Option<Color>, attrs: Attributes, level: ColorLevel) -> String {
+    Style { fg, bg, attrs }.render(text, level)
 }
 
 /// Colorize text using hex color codes
 /// 
+/// Short (`#0FF`) and alpha (`#00FFFF80`) hex forms work too, as does any
+/// other string accepted by [`parse_color`], such as `hsl(180, 100%, 50%)`.
+/// 
 /// # Examples
 /// ```
 /// use make_colors::make_colors_hex;
 /// 
 /// // Cyan text
-/// let colored = make_colors_hex("Cyan text", "#00FFFF", None);
+/// let colored = make_colors_hex("Cyan text", "#00FFFF", None).unwrap();
 /// println!("{}", colored);
 /// 
 /// // With background
-/// let colored = make_colors_hex("Custom", "#FF5500", Some("#001122"));
+/// let colored = make_colors_hex("Custom", "#FF5500", Some("#001122")).unwrap();
 /// println!("{}", colored);
 /// ```
 pub fn make_colors_hex(text: &str, fg_hex: &str, bg_hex: Option<&str>) -> Result<String, MakeColorsError> {
@@ -298,15 +271,10 @@ pub fn make_colors_hex_with_attrs(
     bg_hex: Option<&str>,
     attrs: &[&str]
 ) -> Result<String, MakeColorsError> {
-    let (r, g, b) = hex_to_rgb(fg_hex)?;
-    
-    let bg_rgb = if let Some(bg) = bg_hex {
-        Some(hex_to_rgb(bg)?)
-    } else {
-        None
-    };
+    let fg = parse_color(fg_hex)?;
+    let bg = bg_hex.map(parse_color).transpose()?;
     
-    Ok(make_colors_rgb_with_attrs(text, (r, g, b), bg_rgb, attrs))
+    Ok(paint(text, Some(fg), bg, get_attributes(attrs), support::color_level(Stream::Stdout)))
 }
 
 /// Colorize text using RGB values
@@ -330,28 +298,29 @@ pub fn make_colors_rgb_with_attrs(
     bg_rgb: Option<(u8, u8, u8)>,
     attrs: &[&str]
 ) -> String {
-    let mut result = String::new();
-    
-    // Add attributes
-    for attr in attrs {
-        if let Some(code) = get_attribute_code(attr) {
-            result.push_str(code);
-        }
-    }
-    
-    // Add foreground RGB
-    result.push_str(&format!("\x1b[38;2;{};{};{}m", fg_rgb.0, fg_rgb.1, fg_rgb.2));
-    
-    // Add background RGB if provided
-    if let Some((r, g, b)) = bg_rgb {
-        result.push_str(&format!("\x1b[48;2;{};{};{}m", r, g, b));
-    }
-    
-    // Add text and reset
-    result.push_str(text);
-    result.push_str(ansi::RESET);
-    
-    result
+    let (r, g, b) = fg_rgb;
+    let bg = bg_rgb.map(|(r, g, b)| Color::Rgb(r, g, b));
+    paint(text, Some(Color::Rgb(r, g, b)), bg, get_attributes(attrs), support::color_level(Stream::Stdout))
+}
+
+/// Colorize text using the xterm 256-color palette
+/// 
+/// # Examples
+/// ```
+/// use make_colors::make_colors_256;
+/// 
+/// // Orange on dark gray
+/// let colored = make_colors_256("Orange text", 208, Some(236));
+/// println!("{}", colored);
+/// ```
+pub fn make_colors_256(text: &str, fg: u8, bg: Option<u8>) -> String {
+    make_colors_256_with_attrs(text, fg, bg, &[])
+}
+
+/// Colorize text using the xterm 256-color palette with attributes
+pub fn make_colors_256_with_attrs(text: &str, fg: u8, bg: Option<u8>, attrs: &[&str]) -> String {
+    let bg = bg.map(Color::Ansi256);
+    paint(text, Some(Color::Ansi256(fg)), bg, get_attributes(attrs), support::color_level(Stream::Stdout))
 }
 
 /// Builder pattern for creating colored text
@@ -372,114 +341,194 @@ pub fn make_colors_rgb_with_attrs(
 ///     .underline()
 ///     .build();
 /// println!("{}", hex_colored);
+///
+/// let typed = ColorBuilder::new("Typed Colors")
+///     .fg_color(make_colors::Color::BrightGreen)
+///     .bg_color(make_colors::Color::Black)
+///     .build();
+/// println!("{}", typed);
 /// ```
 pub struct ColorBuilder {
     text: String,
-    fg: Option<String>,
-    bg: Option<String>,
-    fg_rgb: Option<(u8, u8, u8)>,
-    bg_rgb: Option<(u8, u8, u8)>,
-    attrs: Vec<String>,
+    style: Style,
+    stream: Stream,
+    min_contrast: Option<f32>,
 }
 
 impl ColorBuilder {
     pub fn new(text: &str) -> Self {
         Self {
             text: text.to_string(),
-            fg: None,
-            bg: None,
-            fg_rgb: None,
-            bg_rgb: None,
-            attrs: Vec::new(),
+            style: Style::new(),
+            stream: Stream::Stdout,
+            min_contrast: None,
         }
     }
     
+    /// Set the foreground by name, unknown names leave the text uncolored
     pub fn fg(mut self, color: &str) -> Self {
-        self.fg = Some(color.to_string());
+        self.style.fg = color.parse().ok();
         self
     }
     
+    /// Set the background by name, unknown names leave the background unset
     pub fn bg(mut self, color: &str) -> Self {
-        self.bg = Some(color.to_string());
+        self.style.bg = color.parse().ok();
+        self
+    }
+    
+    /// Set the foreground by name, failing on unknown names
+    pub fn try_fg(mut self, color: &str) -> Result<Self, MakeColorsError> {
+        self.style.fg = Some(color.parse()?);
+        Ok(self)
+    }
+    
+    /// Set the background by name, failing on unknown names
+    pub fn try_bg(mut self, color: &str) -> Result<Self, MakeColorsError> {
+        self.style.bg = Some(color.parse()?);
+        Ok(self)
+    }
+    
+    pub fn fg_color(mut self, color: Color) -> Self {
+        self.style.fg = Some(color);
+        self
+    }
+    
+    pub fn bg_color(mut self, color: Color) -> Self {
+        self.style.bg = Some(color);
         self
     }
     
+    /// Set the foreground from a hex color or any string accepted by [`parse_color`]
     pub fn fg_hex(mut self, hex: &str) -> Result<Self, MakeColorsError> {
-        self.fg_rgb = Some(hex_to_rgb(hex)?);
+        self.style.fg = Some(parse_color(hex)?);
         Ok(self)
     }
     
+    /// Set the background from a hex color or any string accepted by [`parse_color`]
     pub fn bg_hex(mut self, hex: &str) -> Result<Self, MakeColorsError> {
-        self.bg_rgb = Some(hex_to_rgb(hex)?);
+        self.style.bg = Some(parse_color(hex)?);
         Ok(self)
     }
     
     pub fn fg_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.fg_rgb = Some((r, g, b));
+        self.style.fg = Some(Color::Rgb(r, g, b));
         self
     }
     
     pub fn bg_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.bg_rgb = Some((r, g, b));
+        self.style.bg = Some(Color::Rgb(r, g, b));
+        self
+    }
+    
+    pub fn fg_256(mut self, index: u8) -> Self {
+        self.style.fg = Some(Color::Ansi256(index));
+        self
+    }
+    
+    pub fn bg_256(mut self, index: u8) -> Self {
+        self.style.bg = Some(Color::Ansi256(index));
         self
     }
     
     pub fn bold(mut self) -> Self {
-        self.attrs.push("bold".to_string());
+        self.style.attrs |= Attributes::BOLD;
         self
     }
     
     pub fn italic(mut self) -> Self {
-        self.attrs.push("italic".to_string());
+        self.style.attrs |= Attributes::ITALIC;
         self
     }
     
     pub fn underline(mut self) -> Self {
-        self.attrs.push("underline".to_string());
+        self.style.attrs |= Attributes::UNDERLINE;
         self
     }
     
     pub fn dim(mut self) -> Self {
-        self.attrs.push("dim".to_string());
+        self.style.attrs |= Attributes::DIM;
         self
     }
     
     pub fn blink(mut self) -> Self {
-        self.attrs.push("blink".to_string());
+        self.style.attrs |= Attributes::BLINK;
         self
     }
     
     pub fn reverse(mut self) -> Self {
-        self.attrs.push("reverse".to_string());
+        self.style.attrs |= Attributes::REVERSE;
+        self
+    }
+    
+    pub fn strikethrough(mut self) -> Self {
+        self.style.attrs |= Attributes::STRIKETHROUGH;
         self
     }
     
+    /// Add an attribute by name, unknown names are ignored
     pub fn attr(mut self, attr: &str) -> Self {
-        self.attrs.push(attr.to_string());
+        if let Some(attr) = Attributes::from_name(attr) {
+            self.style.attrs |= attr;
+        }
         self
     }
     
-    pub fn build(self) -> String {
-        // Prioritize RGB colors over named colors
-        if let Some(fg_rgb) = self.fg_rgb {
-            let attrs_refs: Vec<&str> = self.attrs.iter().map(|s| s.as_str()).collect();
-            make_colors_rgb_with_attrs(&self.text, fg_rgb, self.bg_rgb, &attrs_refs)
-        } else if let Some(fg) = self.fg {
-            let bg_ref = self.bg.as_deref();
-            let attrs_refs: Vec<&str> = self.attrs.iter().map(|s| s.as_str()).collect();
-            make_colors_with_attrs(&self.text, &fg, bg_ref, &attrs_refs)
-        } else {
-            // No colors specified, just return text with attributes
-            let mut result = String::new();
-            for attr in &self.attrs {
-                if let Some(code) = get_attribute_code(attr) {
-                    result.push_str(code);
-                }
-            }
-            result.push_str(&self.text);
-            result.push_str(ansi::RESET);
-            result
+    /// Add a set of attributes
+    pub fn attrs(mut self, attrs: Attributes) -> Self {
+        self.style.attrs |= attrs;
+        self
+    }
+    
+    /// Layer a whole style on top of the current one
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = self.style.patch(style);
+        self
+    }
+    
+    /// Layer the style of a role from the current theme, unknown roles are ignored
+    pub fn role(mut self, role: &str) -> Self {
+        if let Some(style) = theme::role_style(role) {
+            self.style = self.style.patch(style);
+        }
+        self
+    }
+    
+    /// Honor the color support of `stream` instead of stdout
+    pub fn stream(mut self, stream: Stream) -> Self {
+        self.stream = stream;
+        self
+    }
+    
+    /// Keep the foreground readable on the background
+    ///
+    /// When text is built with a known background, the foreground is moved
+    /// towards black or white until its WCAG contrast ratio is at least
+    /// `ratio` (see [`contrast::ensure_contrast`]). Without a foreground,
+    /// black or white is picked.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::contrast::AA;
+    /// use make_colors::ColorBuilder;
+    ///
+    /// // The user picked a yellow background, keep the text readable
+    /// let text = ColorBuilder::new("Status").fg("yellow").bg_hex("#FFD700").unwrap().min_contrast(AA).build();
+    /// println!("{}", text);
+    /// ```
+    pub fn min_contrast(mut self, ratio: f32) -> Self {
+        self.min_contrast = Some(ratio);
+        self
+    }
+    
+    pub fn build(mut self) -> String {
+        if let (Some(ratio), Some(bg)) = (self.min_contrast, self.style.bg.filter(|bg| bg.to_rgb().is_some())) {
+            self.style.fg = Some(match self.style.fg {
+                Some(fg) if fg != Color::Default => contrast::ensure_contrast(fg, bg, ratio),
+                _ => contrast::best_foreground(bg),
+            });
         }
+        self.style.render(&self.text, support::color_level(self.stream))
     }
 }
 
@@ -527,13 +576,19 @@ mod tests {
 
     #[test]
     fn test_make_colors() {
+        support::force_true_color();
         let result = make_colors("Test", "red", None);
         assert!(result.contains("Test"));
         assert!(result.contains("\x1b["));
+
+        // Nested colored text gets the outer color back instead of a reset
+        let nested = make_colors(&format!("a {} c", make_colors("b", "red", None)), "blue", None);
+        assert_eq!(nested, format!("{}a {}b{} c{}", ansi::BLUE, ansi::RED, ansi::BLUE, ansi::RESET));
     }
 
     #[test]
     fn test_make_colors_hex() {
+        support::force_true_color();
         let result = make_colors_hex("Test", "#00FFFF", None).unwrap();
         assert!(result.contains("Test"));
         assert!(result.contains("\x1b[38;2;"));
@@ -546,5 +601,65 @@ mod tests {
             .bold()
             .build();
         assert!(result.contains("Test"));
+
+        support::force_true_color();
+        let result = ColorBuilder::new("Test").role("success").underline().role("nope").build();
+        assert_eq!(result, format!("{}{}Test{}", ansi::UNDERLINE, ansi::GREEN, ansi::RESET));
+
+        let result = ColorBuilder::new("Test").bg_color(Color::Rgb(0, 0, 128)).min_contrast(contrast::AA).build();
+        assert_eq!(result, "\x1b[38;2;255;255;255m\x1b[48;2;0;0;128mTest\x1b[0m");
+        let result = ColorBuilder::new("Test").fg_rgb(250, 250, 250).bg_rgb(255, 255, 255).min_contrast(contrast::AA).build();
+        assert!(!result.contains("250;250;250"), "{:?}", result);
+        let result = ColorBuilder::new("Test").fg("red").min_contrast(contrast::AAA).build();
+        assert_eq!(result, format!("{}Test{}", ansi::RED, ansi::RESET));
+    }
+
+    #[test]
+    fn test_typed_colors() {
+        support::force_true_color();
+        assert_eq!(
+            make_colors_typed("Test", Color::Red, Some(Color::White)),
+            make_colors("Test", "red", Some("white"))
+        );
+        assert!(try_make_colors("Test", "purpel", None).is_err());
+        assert!(ColorBuilder::new("Test").try_bg("redd").is_err());
+
+        let result = ColorBuilder::new("Test").bg_color(Color::Blue).build();
+        assert_eq!(result, format!("{}Test{}", ansi::BG_BLUE, ansi::RESET));
+    }
+
+    #[test]
+    fn test_attributes_deduplicated() {
+        support::force_true_color();
+        let result = make_colors_with_attrs("Test", "red", None, &["bold", "BOLD", "blod", "overline"]);
+        assert_eq!(result, format!("{}{}{}Test{}", ansi::BOLD, ansi::OVERLINE, ansi::RED, ansi::RESET));
+
+        let built = ColorBuilder::new("Test").fg("red").bold().attr("bold").attr("overline").build();
+        assert_eq!(built, result);
+    }
+
+    #[test]
+    fn test_color_level_none() {
+        // Only this test touches stderr, so it can't race with the others
+        support::set_stream_color_level(Stream::Stderr, Some(ColorLevel::None));
+        let result = ColorBuilder::new("Test").fg("red").bold().stream(Stream::Stderr).build();
+        assert_eq!(result, "Test");
+    }
+
+    #[test]
+    fn test_paint_downsamples() {
+        let result = paint("Test", Some(Color::Rgb(255, 136, 0)), None, Attributes::empty(), ColorLevel::Ansi256);
+        assert_eq!(result, "\x1b[38;5;208mTest\x1b[0m");
+
+        let result = paint("Test", None, Some(Color::Rgb(0, 0, 139)), Attributes::empty(), ColorLevel::Ansi16);
+        assert_eq!(result, format!("{}Test{}", ansi::BG_BLUE, ansi::RESET));
+    }
+
+    #[test]
+    fn test_make_colors_256() {
+        support::force_true_color();
+        assert_eq!(make_colors_256("Test", 208, Some(236)), "\x1b[38;5;208m\x1b[48;5;236mTest\x1b[0m");
+        assert_eq!(ColorBuilder::new("Test").fg_256(208).bg_256(236).build(), make_colors_256("Test", 208, Some(236)));
+        assert_eq!(make_colors("Test", "color(208)", None), make_colors_256("Test", 208, None));
     }
 }
\ No newline at end of file
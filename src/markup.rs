@@ -0,0 +1,200 @@
+// File: src\markup.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Rich-style inline markup parser for make_colors.
+// License: MIT
+
+//! Rich-style inline markup.
+//!
+//! Tags are written in square brackets and apply until they are closed:
+//!
+//! - `[bold red on white]text[/]` - attributes, foreground and `on <background>`
+//! - `[#FF8800]text[/]`, `[rgb(255, 136, 0)]text[/]` - hex and RGB colors
+//! - `[error]text[/]`, `[bold info]text[/]` - roles of the current
+//!   [theme](crate::theme), mixed freely with attributes and colors
+//! - `[/]` closes the most recently opened tag, `[/bold]` closes the last tag
+//!   written exactly as `[bold]`
+//! - `\[` produces a literal `[` and `\\` a literal backslash
+//!
+//! Closing a tag restores the style of the tags that are still open, and
+//! tags left open at the end of the input are closed automatically.
+//!
+//! # Examples
+//! ```
+//! use make_colors::markup;
+//!
+//! let colored = markup::render("[bold red]Error:[/] file not found").unwrap();
+//! println!("{}", colored);
+//!
+//! let nested = markup::render("[green]ok [bold]done[/bold] \\[100%][/]").unwrap();
+//! println!("{}", nested);
+//! ```
+
+use crate::support::{self, ColorLevel, Stream};
+use crate::{theme, MakeColorsError, Style};
+
+fn markup_error(position: usize, message: &str) -> MakeColorsError {
+    MakeColorsError::InvalidMarkup {
+        position,
+        message: message.to_string(),
+    }
+}
+
+/// Render markup into a string with ANSI escape codes
+///
+/// Error positions are byte offsets into `markup`. Tags are still validated
+/// when stdout has no color support, but no escape codes are emitted.
+///
+/// # Examples
+/// ```
+/// use make_colors::markup;
+///
+/// let colored = markup::render("[white on red] ERROR [/] disk full").unwrap();
+/// assert!(colored.ends_with(" disk full"));
+///
+/// let err = markup::render("[bold purpel]oops[/]").unwrap_err();
+/// println!("{}", err); // Invalid markup at position 6: unknown style 'purpel'
+/// ```
+pub fn render(markup: &str) -> Result<String, MakeColorsError> {
+    let level = support::color_level(Stream::Stdout);
+    let theme = theme::read_current();
+    let mut result = String::new();
+    let mut stack: Vec<(&str, Style)> = Vec::new();
+    let mut active = Style::new();
+    let mut text = String::new();
+    let mut chars = markup.char_indices().peekable();
+
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '\\' => match chars.peek() {
+                Some(&(_, next @ ('[' | '\\'))) => {
+                    text.push(next);
+                    chars.next();
+                }
+                _ => text.push('\\'),
+            },
+            '[' => {
+                let end = markup[i..]
+                    .find(']')
+                    .map(|e| i + e)
+                    .ok_or_else(|| markup_error(i, "unclosed tag, expected ']'"))?;
+                let tag = &markup[i + 1..end];
+
+                flush(&mut result, &mut text, &mut active, &stack, level);
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim();
+                    if name.is_empty() {
+                        if stack.pop().is_none() {
+                            return Err(markup_error(i, "closing tag '[/]' has nothing to close"));
+                        }
+                    } else {
+                        let index = stack
+                            .iter()
+                            .rposition(|(open, _)| *open == name)
+                            .ok_or_else(|| {
+                                markup_error(i, &format!("closing tag '[/{}]' doesn't match any open tag", name))
+                            })?;
+                        stack.remove(index);
+                    }
+                } else {
+                    if tag.trim().is_empty() {
+                        return Err(markup_error(i, "empty tag"));
+                    }
+                    let style = Style::parse_words(tag, Some(&theme))
+                        .map_err(|(pos, message)| markup_error(i + 1 + pos, &message))?;
+                    stack.push((tag.trim(), style));
+                }
+
+                while let Some(&(j, _)) = chars.peek() {
+                    if j > end {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            _ => text.push(ch),
+        }
+    }
+
+    flush(&mut result, &mut text, &mut active, &stack, level);
+    let _ = active.write_transition(&Style::new(), &mut result, level);
+
+    Ok(result)
+}
+
+/// Write pending text, switching only the parts of the style that changed
+fn flush(
+    result: &mut String,
+    text: &mut String,
+    active: &mut Style,
+    stack: &[(&str, Style)],
+    level: ColorLevel,
+) {
+    if text.is_empty() {
+        return;
+    }
+
+    let style = stack.iter().fold(Style::new(), |style, (_, tag)| style.patch(*tag));
+    let _ = active.write_transition(&style, result, level);
+    *active = style;
+
+    result.push_str(text);
+    text.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ansi;
+
+    #[test]
+    fn test_render_basic_tag() {
+        support::force_true_color();
+        let result = render("[bold red on white]Hi[/]!").unwrap();
+        assert_eq!(result, format!("\x1b[1;31;47mHi{}!", ansi::RESET));
+    }
+
+    #[test]
+    fn test_render_restores_outer_style() {
+        support::force_true_color();
+        let result = render("[red]a[bold]b[/bold]c[/]").unwrap();
+        // Closing a tag only turns off what it added
+        assert_eq!(result, format!("{}a{}b\x1b[22mc{}", ansi::RED, ansi::BOLD, ansi::RESET));
+        let result = render("[red on white]a[blue]b[/]c[/]").unwrap();
+        assert_eq!(result, format!("\x1b[31;47ma\x1b[34mb\x1b[31mc{}", ansi::RESET));
+    }
+
+    #[test]
+    fn test_render_hex_and_rgb() {
+        support::force_true_color();
+        let result = render("[#00FFFF on rgb(1, 2, 3)]x").unwrap();
+        assert_eq!(result, "\x1b[38;2;0;255;255;48;2;1;2;3mx\x1b[0m");
+    }
+
+    #[test]
+    fn test_render_theme_roles() {
+        support::force_true_color();
+        let result = render("[error]x[/] [italic muted on blue]y").unwrap();
+        assert_eq!(result, "\x1b[1;91mx\x1b[0m \x1b[3;90;44my\x1b[0m");
+    }
+
+    #[test]
+    fn test_render_escapes() {
+        assert_eq!(render("\\[red] and \\\\ and ]").unwrap(), "[red] and \\ and ]");
+        assert_eq!(render("plain").unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_render_errors() {
+        let position = |input: &str| match render(input) {
+            Err(MakeColorsError::InvalidMarkup { position, .. }) => position,
+            other => panic!("expected markup error, got {:?}", other),
+        };
+        assert_eq!(position("ab[bold purpel]x"), 8);
+        assert_eq!(position("[red]x[/blue]"), 6);
+        assert_eq!(position("x[/]"), 1);
+        assert_eq!(position("[red on]x"), 5);
+        assert_eq!(position("abc[red"), 3);
+    }
+}
@@ -0,0 +1,725 @@
+// File: src\pretty.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Colored pretty-printing of Debug output with indent guides.
+// License: MIT
+
+//! Pretty-printed `Debug` values.
+//!
+//! [`pretty`] formats a value with `{:#?}`, [`parse`]s the output into a
+//! [`Node`] tree of structs, tuples, lists, maps, strings, numbers and
+//! booleans, and [`render`]s it again with a style per kind of token,
+//! guides along each indentation level, and optional limits on the depth
+//! and on the length of strings. Hand-written `Debug` output that does not
+//! follow the usual shapes is kept as text rather than rejected.
+//!
+//! # Examples
+//! ```
+//! use make_colors::pretty::{self, PrettyOptions};
+//!
+//! #[derive(Debug)]
+//! struct Job {
+//!     id: u32,
+//!     name: String,
+//!     tags: Vec<&'static str>,
+//!     parent: Option<u32>,
+//! }
+//!
+//! let job = Job { id: 7, name: "nightly backup".into(), tags: vec!["db", "s3"], parent: None };
+//! println!("{}", pretty::pretty(&job, &PrettyOptions::default()));
+//!
+//! let short = PrettyOptions::default().max_depth(1).max_string(8).no_guides();
+//! assert_eq!(
+//!     pretty::pretty(&job, &short).text(),
+//!     "Job {\n    id: 7,\n    name: \"nightly \u{2026}\",\n    tags: [..],\n    parent: None,\n}"
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::{Attributes, Color, Style, StyledString};
+
+/// A parsed `Debug` value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Node {
+    /// `Name { field: value }`; `non_exhaustive` when it ends with `..`
+    Struct {
+        name: String,
+        fields: Vec<(String, Node)>,
+        non_exhaustive: bool,
+    },
+    /// `Name(a, b)`, or `(a, b)` with an empty name
+    Tuple { name: String, items: Vec<Node> },
+    /// `[a, b]`
+    List(Vec<Node>),
+    /// `{a, b}`
+    Set(Vec<Node>),
+    /// `{key: value}`
+    Map(Vec<(Node, Node)>),
+    /// A string or character literal, with its quotes and escapes
+    String(String),
+    Number(String),
+    Bool(bool),
+    /// A unit struct or variant, such as `None`
+    Name(String),
+    /// Text that has none of the shapes above
+    Other(String),
+}
+
+impl Node {
+    /// Whether the node has no children
+    pub fn is_scalar(&self) -> bool {
+        !matches!(self, Node::Struct { .. } | Node::Tuple { .. } | Node::List(_) | Node::Set(_) | Node::Map(_))
+    }
+}
+
+/// Styles and limits for [`render`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrettyOptions {
+    /// Struct, tuple struct and variant names
+    pub name: Style,
+    /// Field names
+    pub field: Style,
+    pub string: Style,
+    pub number: Style,
+    pub boolean: Style,
+    /// `None` and `Some`
+    pub none: Style,
+    /// Brackets, commas, colons and elisions
+    pub punctuation: Style,
+    /// Indentation guides
+    pub guide: Style,
+    /// Text that could not be parsed
+    pub other: Style,
+    /// Columns per indentation level
+    pub indent: usize,
+    /// Draw a `│` guide at each indentation level
+    pub guides: bool,
+    /// Nesting level from which containers are shown as `..`; the value
+    /// itself is level 0
+    pub max_depth: Option<usize>,
+    /// Characters of a string shown before it is cut off with `…`; an escape
+    /// such as `\u{1F600}` counts as one
+    pub max_string: Option<usize>,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            name: Style::new().fg(Color::BrightMagenta).attrs(Attributes::BOLD),
+            field: Style::new().fg(Color::Yellow),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Cyan).attrs(Attributes::BOLD),
+            boolean: Style::new().fg(Color::BrightGreen).attrs(Attributes::ITALIC),
+            none: Style::new().fg(Color::Magenta).attrs(Attributes::ITALIC),
+            punctuation: Style::new(),
+            guide: Style::new().fg(Color::BrightBlack),
+            other: Style::new(),
+            indent: 4,
+            guides: true,
+            max_depth: None,
+            max_string: None,
+        }
+    }
+}
+
+impl PrettyOptions {
+    /// Options without any styles, keeping the layout and limits
+    pub fn plain() -> Self {
+        PrettyOptions {
+            name: Style::new(),
+            field: Style::new(),
+            string: Style::new(),
+            number: Style::new(),
+            boolean: Style::new(),
+            none: Style::new(),
+            guide: Style::new(),
+            ..PrettyOptions::default()
+        }
+    }
+
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn no_guides(mut self) -> Self {
+        self.guides = false;
+        self
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    pub fn max_string(mut self, length: usize) -> Self {
+        self.max_string = Some(length);
+        self
+    }
+}
+
+/// Pretty-print a `Debug` value
+pub fn pretty<T: fmt::Debug + ?Sized>(value: &T, options: &PrettyOptions) -> StyledString {
+    render(&parse(&format!("{:#?}", value)), options)
+}
+
+/// Parse `{:?}` or `{:#?}` output
+///
+/// Input with trailing text that does not belong to the value is returned
+/// as a single [`Node::Other`].
+///
+/// # Examples
+/// ```
+/// use make_colors::pretty::{parse, Node};
+///
+/// let node = parse("Some((1, \"a\"))");
+/// let pair = Node::Tuple { name: String::new(), items: vec![Node::Number("1".into()), Node::String("\"a\"".into())] };
+/// assert_eq!(node, Node::Tuple { name: "Some".into(), items: vec![pair] });
+/// ```
+pub fn parse(debug: &str) -> Node {
+    let mut parser = Parser { text: debug, position: 0 };
+    let node = parser.value();
+    parser.skip_whitespace();
+    if parser.position < debug.len() {
+        return Node::Other(debug.trim().to_string());
+    }
+    node
+}
+
+/// Lay out a node tree like `{:#?}` does, with styles and limits
+///
+/// A tuple holding a single scalar, such as `Some(3)`, stays on one line.
+pub fn render(node: &Node, options: &PrettyOptions) -> StyledString {
+    let mut renderer = Renderer {
+        options,
+        output: StyledString::new(),
+    };
+    renderer.node(node, 0);
+    renderer.output
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position = self.text.len() - self.rest().trim_start().len();
+    }
+
+    /// Skip whitespace and consume `token` if it comes next
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(token);
+        if found {
+            self.position += token.len();
+        }
+        found
+    }
+
+    fn take_while(&mut self, mut accept: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c: char| !accept(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn value(&mut self) -> Node {
+        self.skip_whitespace();
+        let Some(c) = self.peek() else {
+            return Node::Other(String::new());
+        };
+        match c {
+            '"' | '\'' => Node::String(self.literal(c)),
+            '[' => Node::List(self.items('[', ']')),
+            '(' => Node::Tuple {
+                name: String::new(),
+                items: self.items('(', ')'),
+            },
+            '{' => self.map_or_set(),
+            '-' | '0'..='9' => self.number(),
+            c if c.is_alphabetic() || c == '_' => self.named(),
+            _ => self.other(),
+        }
+    }
+
+    /// A quoted literal, keeping escapes as written
+    fn literal(&mut self, quote: char) -> String {
+        let start = self.position;
+        let mut chars = self.rest().char_indices().skip(1);
+        let mut end = self.text.len();
+        while let Some((i, c)) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c == quote {
+                end = start + i + 1;
+                break;
+            }
+        }
+        self.position = end;
+        self.text[start..end].to_string()
+    }
+
+    fn number(&mut self) -> Node {
+        let start = self.position;
+        let mut previous = '\0';
+        self.take_while(|c| {
+            let accepted = c.is_alphanumeric() || c == '.' || c == '_' || (matches!(c, '-' | '+') && matches!(previous, '\0' | 'e' | 'E'));
+            previous = c;
+            accepted
+        });
+        match &self.text[start..self.position] {
+            "-" => {
+                self.position = start;
+                self.other()
+            }
+            number => Node::Number(number.to_string()),
+        }
+    }
+
+    /// A name, possibly followed by struct fields or tuple items
+    fn named(&mut self) -> Node {
+        let start = self.position;
+        while !self.take_while(|c| c.is_alphanumeric() || c == '_').is_empty() && self.rest().starts_with("::") {
+            self.position += 2;
+        }
+        self.generics();
+        let name = self.text[start..self.position].to_string();
+
+        let after_name = self.position;
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => {
+                let (fields, non_exhaustive) = self.fields();
+                Node::Struct {
+                    name,
+                    fields,
+                    non_exhaustive,
+                }
+            }
+            Some('(') if after_name == self.position => Node::Tuple {
+                name,
+                items: self.items('(', ')'),
+            },
+            _ => {
+                self.position = after_name;
+                match name.as_str() {
+                    "true" => Node::Bool(true),
+                    "false" => Node::Bool(false),
+                    "inf" | "NaN" => Node::Number(name),
+                    _ => Node::Name(name),
+                }
+            }
+        }
+    }
+
+    /// Consume balanced generic arguments such as `<u8>` or `<Vec<T>, 2>`
+    fn generics(&mut self) {
+        if !self.rest().starts_with('<') {
+            return;
+        }
+        let mut depth = 0;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.position += i + 1;
+                        return;
+                    }
+                }
+                '\n' | '{' | '}' | '(' | ')' | '[' | ']' | '"' => return,
+                _ => {}
+            }
+        }
+    }
+
+    /// Comma-separated values between `open` and `close`
+    fn items(&mut self, open: char, close: char) -> Vec<Node> {
+        self.position += open.len_utf8();
+        let mut items = Vec::new();
+        let close = close.to_string();
+        while !self.eat(&close) && self.position < self.text.len() {
+            items.push(self.value());
+            self.eat(",");
+        }
+        items
+    }
+
+    fn fields(&mut self) -> (Vec<(String, Node)>, bool) {
+        self.position += 1;
+        let mut fields = Vec::new();
+        let mut non_exhaustive = false;
+        while !self.eat("}") && self.position < self.text.len() {
+            if self.eat("..") {
+                non_exhaustive = true;
+                continue;
+            }
+            let name = self.take_while(|c| c.is_alphanumeric() || c == '_').to_string();
+            if !name.is_empty() && !self.eat(":") {
+                // Not a field after all
+                fields.push((String::new(), Node::Other(name)));
+            } else {
+                fields.push((name, self.value()));
+            }
+            self.eat(",");
+        }
+        (fields, non_exhaustive)
+    }
+
+    fn map_or_set(&mut self) -> Node {
+        self.position += 1;
+        let mut entries = Vec::new();
+        while !self.eat("}") && self.position < self.text.len() {
+            let key = self.value();
+            let value = self.eat(":").then(|| self.value());
+            entries.push((key, value));
+            self.eat(",");
+        }
+
+        if !entries.is_empty() && entries.iter().all(|(_, value)| value.is_none()) {
+            Node::Set(entries.into_iter().map(|(key, _)| key).collect())
+        } else {
+            let entries = entries.into_iter().map(|(key, value)| (key, value.unwrap_or(Node::Other(String::new()))));
+            Node::Map(entries.collect())
+        }
+    }
+
+    /// Text up to the next separator outside brackets, or one character
+    fn other(&mut self) -> Node {
+        let start = self.position;
+        let mut depth = 0usize;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' | ',' if depth == 0 => {
+                    self.position = start + i;
+                    break;
+                }
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+            self.position = start + i + c.len_utf8();
+        }
+        if self.position == start {
+            self.position += self.peek().map_or(0, char::len_utf8);
+        }
+        Node::Other(self.text[start..self.position].trim_end().to_string())
+    }
+}
+
+struct Renderer<'a> {
+    options: &'a PrettyOptions,
+    output: StyledString,
+}
+
+impl Renderer<'_> {
+    fn punctuation(&mut self, text: &str) {
+        self.output.push_styled(text, self.options.punctuation);
+    }
+
+    fn new_line(&mut self, depth: usize) {
+        self.output.push_str("\n");
+        let indent = self.options.indent.max(1);
+        for _ in 0..depth {
+            if self.options.guides {
+                self.output.push_styled("│", self.options.guide);
+                self.output.push_str(&" ".repeat(indent - 1));
+            } else {
+                self.output.push_str(&" ".repeat(indent));
+            }
+        }
+    }
+
+    fn name(&mut self, name: &str) {
+        let style = match name {
+            "None" | "Some" => self.options.none,
+            _ => self.options.name,
+        };
+        self.output.push_styled(name, style);
+    }
+
+    fn node(&mut self, node: &Node, depth: usize) {
+        let options = self.options;
+        match node {
+            Node::Struct {
+                name,
+                fields,
+                non_exhaustive,
+            } => {
+                self.name(name);
+                if fields.is_empty() && !non_exhaustive {
+                    return;
+                }
+                self.container(" {", "}", fields.len() + *non_exhaustive as usize, depth, |renderer, index| {
+                    match fields.get(index) {
+                        Some((field, value)) => {
+                            if !field.is_empty() {
+                                renderer.output.push_styled(field, renderer.options.field);
+                                renderer.punctuation(": ");
+                            }
+                            renderer.node(value, depth + 1);
+                        }
+                        None => renderer.punctuation(".."),
+                    }
+                });
+            }
+            Node::Tuple { name, items } => {
+                self.name(name);
+                if let [item] = items.as_slice() {
+                    if item.is_scalar() {
+                        self.punctuation("(");
+                        self.node(item, depth + 1);
+                        self.punctuation(")");
+                        return;
+                    }
+                }
+                self.container("(", ")", items.len(), depth, |renderer, index| renderer.node(&items[index], depth + 1));
+            }
+            Node::List(items) => {
+                self.container("[", "]", items.len(), depth, |renderer, index| renderer.node(&items[index], depth + 1));
+            }
+            Node::Set(items) => {
+                self.container("{", "}", items.len(), depth, |renderer, index| renderer.node(&items[index], depth + 1));
+            }
+            Node::Map(entries) => self.container("{", "}", entries.len(), depth, |renderer, index| {
+                renderer.node(&entries[index].0, depth + 1);
+                renderer.punctuation(": ");
+                renderer.node(&entries[index].1, depth + 1);
+            }),
+            Node::String(literal) => {
+                let text = truncate_literal(literal, options.max_string);
+                self.output.push_styled(&text, options.string);
+            }
+            Node::Number(number) => self.output.push_styled(number, options.number),
+            Node::Bool(value) => self.output.push_styled(if *value { "true" } else { "false" }, options.boolean),
+            Node::Name(name) => self.name(name),
+            Node::Other(text) => self.output.push_styled(text, options.other),
+        }
+    }
+
+    /// Entries between `open` and `close`, one per line
+    fn container(&mut self, open: &str, close: &str, count: usize, depth: usize, mut entry: impl FnMut(&mut Self, usize)) {
+        self.punctuation(open);
+        if count == 0 {
+            self.punctuation(close.trim());
+            return;
+        }
+        if self.options.max_depth.is_some_and(|max| depth >= max) {
+            self.punctuation("..");
+            self.punctuation(close);
+            return;
+        }
+
+        for index in 0..count {
+            self.new_line(depth + 1);
+            entry(self, index);
+            self.punctuation(",");
+        }
+        self.new_line(depth);
+        self.punctuation(close);
+    }
+}
+
+/// A literal cut to `max` characters between its quotes, ending in `…`
+///
+/// An escape such as `\n` or `\u{1F600}` counts as one character and is
+/// never cut apart.
+fn truncate_literal(literal: &str, max: Option<usize>) -> String {
+    let Some(max) = max else {
+        return literal.to_string();
+    };
+    let Some(quote) = literal.chars().next() else {
+        return String::new();
+    };
+    let content = &literal[quote.len_utf8()..];
+    let content = match content.strip_suffix(quote) {
+        Some(inner) if !content.ends_with(&format!("\\{}", quote)) || inner.ends_with("\\\\") => inner,
+        _ => content,
+    };
+
+    // Byte offset where each character or escape starts
+    let mut starts = Vec::new();
+    let mut chars = content.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        starts.push(i);
+        if c != '\\' {
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'u')) if chars.peek().is_some_and(|(_, c)| *c == '{') => {
+                for (_, c) in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                }
+            }
+            Some((_, 'x')) => {
+                chars.next();
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+
+    match starts.get(max) {
+        Some(&cut) => format!("{}{}\u{2026}{}", quote, &content[..cut], quote),
+        None => literal.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Config {
+        name: &'static str,
+        ratio: f64,
+        retries: Option<u8>,
+        point: (i32, i32),
+        flags: BTreeMap<&'static str, bool>,
+        unit: Unit,
+        wrapper: Wrapper,
+    }
+
+    #[derive(Debug)]
+    struct Unit;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Wrapper(Vec<u8>);
+
+    fn config() -> Config {
+        Config {
+            name: "main \"db\"",
+            ratio: -1.5e-7,
+            retries: Some(3),
+            point: (1, -2),
+            flags: BTreeMap::from([("fast", true)]),
+            unit: Unit,
+            wrapper: Wrapper(vec![]),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let Node::Struct { name, fields, non_exhaustive } = parse(&format!("{:#?}", config())) else {
+            panic!("not a struct");
+        };
+        assert_eq!(name, "Config");
+        assert!(!non_exhaustive);
+        let values: Vec<_> = fields.iter().map(|(field, value)| (field.as_str(), value.clone())).collect();
+        assert_eq!(values[0], ("name", Node::String(r#""main \"db\"""#.into())));
+        assert_eq!(values[1], ("ratio", Node::Number("-1.5e-7".into())));
+        assert_eq!(values[3].1, Node::Tuple { name: String::new(), items: vec![Node::Number("1".into()), Node::Number("-2".into())] });
+        assert_eq!(values[4].1, Node::Map(vec![(Node::String("\"fast\"".into()), Node::Bool(true))]));
+        assert_eq!(values[5].1, Node::Name("Unit".into()));
+        assert_eq!(values[6].1, Node::Tuple { name: "Wrapper".into(), items: vec![Node::List(vec![])] });
+        // The compact form parses to the same tree
+        assert_eq!(parse(&format!("{:?}", config())), parse(&format!("{:#?}", config())));
+
+        assert_eq!(parse("{1, 2}"), Node::Set(vec![Node::Number("1".into()), Node::Number("2".into())]));
+        assert_eq!(
+            parse("Conn { id: 1, .. }"),
+            Node::Struct { name: "Conn".into(), fields: vec![("id".into(), Node::Number("1".into()))], non_exhaustive: true }
+        );
+        assert_eq!(parse("Id<3> => 4"), Node::Other("Id<3> => 4".into()));
+        assert_eq!(parse("[<opaque>, 2]"), Node::List(vec![Node::Other("<opaque>".into()), Node::Number("2".into())]));
+    }
+
+    #[test]
+    fn test_generic_names() {
+        use std::marker::PhantomData;
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Holder {
+            a: PhantomData<u8>,
+            b: u8,
+            c: Vec<PhantomData<Vec<u8>>>,
+        }
+
+        let holder = Holder { a: PhantomData, b: 1, c: vec![PhantomData] };
+        let Node::Struct { fields, .. } = parse(&format!("{:#?}", holder)) else {
+            panic!("not a struct");
+        };
+        assert_eq!(
+            fields,
+            [
+                ("a".to_string(), Node::Name("PhantomData<u8>".into())),
+                ("b".to_string(), Node::Number("1".into())),
+                ("c".to_string(), Node::List(vec![Node::Name("PhantomData<alloc::vec::Vec<u8>>".into())])),
+            ]
+        );
+        assert_eq!(
+            pretty(&holder, &PrettyOptions::plain().no_guides()).text(),
+            "Holder {\n    a: PhantomData<u8>,\n    b: 1,\n    c: [\n        PhantomData<alloc::vec::Vec<u8>>,\n    ],\n}"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let text = pretty(&config(), &PrettyOptions::plain().indent(2)).text();
+        assert_eq!(
+            text,
+            [
+                "Config {",
+                "│ name: \"main \\\"db\\\"\",",
+                "│ ratio: -1.5e-7,",
+                "│ retries: Some(3),",
+                "│ point: (",
+                "│ │ 1,",
+                "│ │ -2,",
+                "│ ),",
+                "│ flags: {",
+                "│ │ \"fast\": true,",
+                "│ },",
+                "│ unit: Unit,",
+                "│ wrapper: Wrapper(",
+                "│ │ [],",
+                "│ ),",
+                "}",
+            ]
+            .join("\n")
+        );
+
+        let styled = pretty(&Some(true), &PrettyOptions::default());
+        let spans: Vec<_> = styled.spans().iter().map(|span| (span.text.as_str(), span.style)).collect();
+        let options = PrettyOptions::default();
+        assert_eq!(spans, [("Some", options.none), ("(", options.punctuation), ("true", options.boolean), (")", options.punctuation)]);
+    }
+
+    #[test]
+    fn test_limits() {
+        let options = PrettyOptions::plain().no_guides().max_depth(1).max_string(4);
+        assert_eq!(
+            pretty(&config(), &options).text().lines().take(5).collect::<Vec<_>>(),
+            ["Config {", "    name: \"main…\",", "    ratio: -1.5e-7,", "    retries: Some(3),", "    point: (..),"]
+        );
+        assert_eq!(pretty(&vec![1], &PrettyOptions::plain().max_depth(0)).text(), "[..]");
+
+        // Escapes count as one character and are never cut apart
+        assert_eq!(truncate_literal("\"ab\\ncd\"", Some(2)), "\"ab…\"");
+        assert_eq!(truncate_literal("\"ab\\ncd\"", Some(3)), "\"ab\\n…\"");
+        assert_eq!(truncate_literal("\"ab\\u{1F600}cd\"", Some(2)), "\"ab…\"");
+        assert_eq!(truncate_literal("\"ab\\u{1F600}cd\"", Some(3)), "\"ab\\u{1F600}…\"");
+        assert_eq!(truncate_literal("\"\\x7f\\\\z\"", Some(2)), "\"\\x7f\\\\…\"");
+        assert_eq!(truncate_literal("\"a\\\"\"", Some(2)), "\"a\\\"\"");
+        assert_eq!(truncate_literal("'x'", Some(1)), "'x'");
+        assert_eq!(truncate_literal("\"日本語\"", Some(2)), "\"日本…\"");
+    }
+}
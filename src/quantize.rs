@@ -0,0 +1,206 @@
+// File: src\quantize.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Truecolor downsampling to the 256 and 16 color palettes.
+// License: MIT
+
+//! Color quantization.
+//!
+//! Terminals without true color support get the nearest palette entry
+//! instead of a `38;2;r;g;b` sequence. Distances are measured in the
+//! perceptually uniform OKLab space, so e.g. a dark orange maps to an
+//! orange rather than to whichever entry is closest in raw RGB.
+//!
+//! # Examples
+//! ```
+//! use make_colors::quantize::{rgb_to_ansi16, rgb_to_ansi256};
+//! use make_colors::Color;
+//!
+//! assert_eq!(rgb_to_ansi256(255, 136, 0), 208);
+//! assert_eq!(rgb_to_ansi16(250, 20, 20), Color::BrightRed);
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::colorspace::{Oklab, Rgb};
+use crate::{Color, ColorLevel};
+
+/// The 16 ANSI colors in xterm's default palette, in `Color::Black..BrightWhite` order
+pub const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The 16 ANSI colors in palette order
+pub(crate) const ANSI16_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// Channel values of the 6x6x6 color cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// RGB value of an entry in the xterm 256-color palette
+///
+/// # Examples
+/// ```
+/// use make_colors::quantize::ansi256_to_rgb;
+///
+/// assert_eq!(ansi256_to_rgb(9), (255, 0, 0));
+/// assert_eq!(ansi256_to_rgb(208), (255, 135, 0));
+/// assert_eq!(ansi256_to_rgb(244), (128, 128, 128));
+/// ```
+pub fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Nearest entry of the 256-color palette
+///
+/// Only the color cube and the grayscale ramp (16-255) are considered, since
+/// the first 16 entries are commonly redefined by terminal themes.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    static PALETTE: OnceLock<Vec<[f32; 3]>> = OnceLock::new();
+    let palette = PALETTE.get_or_init(|| {
+        (16..=255)
+            .map(|i| {
+                let (r, g, b) = ansi256_to_rgb(i);
+                oklab(r, g, b)
+            })
+            .collect()
+    });
+
+    16 + nearest(palette, oklab(r, g, b)) as u8
+}
+
+/// Nearest of the 16 ANSI colors
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    static PALETTE: OnceLock<Vec<[f32; 3]>> = OnceLock::new();
+    let palette = PALETTE.get_or_init(|| ANSI16_PALETTE.iter().map(|&(r, g, b)| oklab(r, g, b)).collect());
+
+    ANSI16_COLORS[nearest(palette, oklab(r, g, b))]
+}
+
+/// Convert `color` to something `level` can display
+///
+/// Standard colors and `Default` are kept as they are; true colors and
+/// 256-color indexes are mapped down to the nearest palette entry.
+///
+/// # Examples
+/// ```
+/// use make_colors::quantize::downsample;
+/// use make_colors::{Color, ColorLevel};
+///
+/// let orange = Color::Rgb(255, 136, 0);
+/// assert_eq!(downsample(orange, ColorLevel::TrueColor), orange);
+/// assert_eq!(downsample(orange, ColorLevel::Ansi256), Color::Ansi256(208));
+/// assert_eq!(downsample(Color::Ansi256(9), ColorLevel::Ansi16), Color::BrightRed);
+/// ```
+pub fn downsample(color: Color, level: ColorLevel) -> Color {
+    match (color, level) {
+        (Color::Rgb(r, g, b), ColorLevel::Ansi256) => Color::Ansi256(rgb_to_ansi256(r, g, b)),
+        (Color::Rgb(r, g, b), ColorLevel::Ansi16) => rgb_to_ansi16(r, g, b),
+        (Color::Ansi256(n), ColorLevel::Ansi16) if n < 16 => ANSI16_COLORS[n as usize],
+        (Color::Ansi256(n), ColorLevel::Ansi16) => {
+            let (r, g, b) = ansi256_to_rgb(n);
+            rgb_to_ansi16(r, g, b)
+        }
+        _ => color,
+    }
+}
+
+fn nearest(palette: &[[f32; 3]], target: [f32; 3]) -> usize {
+    let distance = |c: &[f32; 3]| {
+        (c[0] - target[0]).powi(2) + (c[1] - target[1]).powi(2) + (c[2] - target[2]).powi(2)
+    };
+
+    palette
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// sRGB to OKLab
+fn oklab(r: u8, g: u8, b: u8) -> [f32; 3] {
+    let Oklab { l, a, b } = Rgb::from_u8(r, g, b).into();
+    [l, a, b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xterm_palette_round_trip() {
+        for index in 16..=255u8 {
+            let (r, g, b) = ansi256_to_rgb(index);
+            assert_eq!(rgb_to_ansi256(r, g, b), index, "index {}", index);
+        }
+        for (i, &(r, g, b)) in ANSI16_PALETTE.iter().enumerate() {
+            assert_eq!(rgb_to_ansi16(r, g, b), ANSI16_COLORS[i]);
+        }
+    }
+
+    #[test]
+    fn test_xterm_palette_values() {
+        assert_eq!(ansi256_to_rgb(16), (0, 0, 0));
+        assert_eq!(ansi256_to_rgb(21), (0, 0, 255));
+        assert_eq!(ansi256_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi256_to_rgb(231), (255, 255, 255));
+        assert_eq!(ansi256_to_rgb(232), (8, 8, 8));
+        assert_eq!(ansi256_to_rgb(255), (238, 238, 238));
+    }
+
+    #[test]
+    fn test_nearest_colors() {
+        assert_eq!(rgb_to_ansi256(250, 250, 250), 231);
+        assert_eq!(rgb_to_ansi256(100, 100, 100), 241);
+        assert_eq!(rgb_to_ansi16(139, 0, 0), Color::Red);
+        assert_eq!(rgb_to_ansi16(255, 140, 0), Color::Yellow);
+        assert_eq!(rgb_to_ansi16(30, 30, 30), Color::Black);
+        assert_eq!(downsample(Color::Blue, ColorLevel::Ansi16), Color::Blue);
+    }
+}
@@ -0,0 +1,125 @@
+// File: src\serde_impl.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Serde support for colors, attributes, styles and themes.
+// License: MIT
+
+//! `Serialize` and `Deserialize` implementations, enabled by the `serde`
+//! feature.
+//!
+//! Colors, attribute sets and styles are written as the same strings their
+//! `Display` implementations produce and read with their `FromStr` parsers,
+//! so `"lightred"`, `"#FF8800"`, `"bold underline"` and `"italic cyan on
+//! black"` all work in configuration files. A [`Theme`] is a table with an
+//! optional `name` and a `roles` table of styles.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{Attributes, Color, Style, Theme};
+
+/// Deserialize any `FromStr` type from a string
+struct FromStrVisitor<T>(&'static str, std::marker::PhantomData<T>);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+macro_rules! string_serde {
+    ($($ty:ty => $expecting:literal),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor::<$ty>($expecting, std::marker::PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+string_serde!(
+    Color => "a color such as \"lightred\", \"#FF8800\" or \"hsl(30, 100%, 50%)\"",
+    Attributes => "attribute names such as \"bold underline\"",
+    Style => "a style such as \"bold red on white\""
+);
+
+/// On-disk layout of a theme
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ThemeData {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    roles: BTreeMap<String, Style>,
+}
+
+impl From<ThemeData> for Theme {
+    fn from(data: ThemeData) -> Self {
+        data.roles
+            .into_iter()
+            .fold(Theme::new(&data.name), |theme, (role, style)| theme.with(&role, style))
+    }
+}
+
+impl From<Theme> for ThemeData {
+    fn from(theme: Theme) -> Self {
+        ThemeData {
+            name: theme.name().to_string(),
+            roles: theme.iter().map(|(role, style)| (role.to_string(), style)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let style: Style = "bold italic #FF8800 on ansi(236)".parse().unwrap();
+        let json = serde_json::to_string(&style).unwrap();
+        assert_eq!(json, "\"bold italic #FF8800 on ansi(236)\"");
+        assert_eq!(serde_json::from_str::<Style>(&json).unwrap(), style);
+
+        let theme = Theme::builtin("monokai").unwrap();
+        let toml = toml::to_string(&theme).unwrap();
+        assert_eq!(toml::from_str::<Theme>(&toml).unwrap(), theme);
+    }
+
+    #[test]
+    fn test_accepted_strings() {
+        let colors: Vec<Color> = serde_json::from_str(r##"["lightr", "#00FFFF", "00FF00", "rgb(1, 2, 3)", "default"]"##).unwrap();
+        assert_eq!(
+            colors,
+            [Color::BrightRed, Color::Rgb(0, 255, 255), Color::Rgb(0, 255, 0), Color::Rgb(1, 2, 3), Color::Default]
+        );
+        assert_eq!(
+            serde_json::from_str::<Attributes>("\"bold, strike\"").unwrap(),
+            Attributes::BOLD | Attributes::STRIKETHROUGH
+        );
+        assert!(serde_json::from_str::<Color>("\"purpel\"").is_err());
+        assert!(serde_json::from_str::<Style>("42").is_err());
+    }
+}
@@ -0,0 +1,263 @@
+// File: src\spans.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: SGR escape parser producing styled spans, and rendering back to escapes.
+// License: MIT
+
+//! Styled spans of already colored text.
+//!
+//! [`parse`] reads text containing SGR escape sequences (as produced by this
+//! crate or by other programs) and splits it into [`Span`]s of text with the
+//! [`Style`] in effect. Basic, bright, 256-color and true colors are
+//! understood, as are every attribute in the [`ansi`](crate::ansi) module and
+//! their "off" codes. Unknown parameters are ignored, and other escape
+//! sequences, malformed or cut off, are dropped from the text.
+//!
+//! [`render`] turns spans back into a string, switching escape codes only
+//! where the style changes, so `render(&parse(text))` also cleans up the
+//! reset-and-reapply noise of concatenated colored strings.
+//!
+//! # Examples
+//! ```
+//! use make_colors::spans::{self, Span};
+//! use make_colors::{Color, Style};
+//!
+//! let parsed = spans::parse("\x1b[1;31mError\x1b[0m: disk full");
+//! assert_eq!(parsed[0], Span::new("Error", "bold red".parse::<Style>().unwrap()));
+//! assert_eq!(parsed[1], Span::new(": disk full", Style::new()));
+//!
+//! // Re-style the error part
+//! let restyled: Vec<Span> = parsed
+//!     .into_iter()
+//!     .map(|span| Span { style: span.style.fg(Color::Yellow), ..span })
+//!     .collect();
+//! println!("{}", spans::render(&restyled));
+//! ```
+
+use crate::ansi::{self, Segment};
+use crate::quantize::ANSI16_COLORS;
+use crate::support::{self, ColorLevel, Stream};
+use crate::{Attributes, Color, Style};
+
+/// A run of text displayed with a single style
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Span {
+    pub fn new(text: impl Into<String>, style: Style) -> Self {
+        Span {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+/// Every underline variant, all turned off by SGR 24
+pub(crate) const UNDERLINES: Attributes = Attributes::UNDERLINE
+    .union(Attributes::DOUBLE_UNDERLINE)
+    .union(Attributes::CURLY_UNDERLINE)
+    .union(Attributes::DOTTED_UNDERLINE)
+    .union(Attributes::DASHED_UNDERLINE);
+
+/// Split text with SGR escapes into styled spans
+///
+/// Adjacent text with the same style ends up in one span and empty spans
+/// are never produced, so the result is the shortest description of the
+/// input.
+///
+/// # Examples
+/// ```
+/// use make_colors::spans;
+/// use make_colors::Color;
+///
+/// let parsed = spans::parse("\x1b[38;2;255;136;0morange\x1b[39m \x1b[48;5;236mdark");
+/// assert_eq!(parsed.len(), 3);
+/// assert_eq!(parsed[0].style.fg, Some(Color::Rgb(255, 136, 0)));
+/// assert_eq!(parsed[2].style.bg, Some(Color::Ansi256(236)));
+/// ```
+pub fn parse(text: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut style = Style::new();
+
+    for segment in ansi::segments(text) {
+        match segment {
+            Segment::Escape(escape) => {
+                if let Some(params) = ansi::sgr_params(escape) {
+                    apply_sgr(&mut style, params);
+                }
+            }
+            Segment::Text(text) => match spans.last_mut() {
+                Some(last) if last.style == style => last.text.push_str(text),
+                _ => spans.push(Span::new(text, style)),
+            },
+        }
+    }
+
+    spans
+}
+
+/// Render spans into a string with ANSI escape codes
+///
+/// Escape codes are only written where the style changes, and only for
+/// what changes (see [`Style::write_transition`]), followed by a final
+/// reset. Colors are downsampled to the color support of stdout.
+pub fn render(spans: &[Span]) -> String {
+    render_at(spans, support::color_level(Stream::Stdout))
+}
+
+/// Render spans for a terminal with `level` color support
+pub(crate) fn render_at(spans: &[Span], level: ColorLevel) -> String {
+    let mut result = String::new();
+    let mut active = Style::new();
+
+    for span in spans.iter().filter(|span| !span.text.is_empty()) {
+        let _ = active.write_transition(&span.style, &mut result, level);
+        active = span.style;
+        result.push_str(&span.text);
+    }
+    let _ = active.write_transition(&Style::new(), &mut result, level);
+
+    result
+}
+
+/// Apply the parameters of one SGR sequence to `style`
+pub(crate) fn apply_sgr(style: &mut Style, params: &str) {
+    let mut params = params.split(';');
+
+    while let Some(param) = params.next() {
+        if param.contains(':') {
+            apply_subparams(style, param);
+            continue;
+        }
+
+        let code = if param.is_empty() { 0 } else { param.parse::<u16>().unwrap_or(u16::MAX) };
+        match code {
+            0 => *style = Style::new(),
+            22 => style.attrs -= Attributes::BOLD | Attributes::DIM,
+            23 => style.attrs -= Attributes::ITALIC,
+            24 => style.attrs -= UNDERLINES,
+            25 => style.attrs -= Attributes::BLINK | Attributes::RAPID_BLINK,
+            27 => style.attrs -= Attributes::REVERSE,
+            28 => style.attrs -= Attributes::HIDDEN,
+            29 => style.attrs -= Attributes::STRIKETHROUGH,
+            54 => style.attrs -= Attributes::FRAMED | Attributes::ENCIRCLED,
+            55 => style.attrs -= Attributes::OVERLINE,
+            30..=37 => style.fg = Some(ANSI16_COLORS[(code - 30) as usize]),
+            90..=97 => style.fg = Some(ANSI16_COLORS[(code - 90 + 8) as usize]),
+            40..=47 => style.bg = Some(ANSI16_COLORS[(code - 40) as usize]),
+            100..=107 => style.bg = Some(ANSI16_COLORS[(code - 100 + 8) as usize]),
+            39 => style.fg = None,
+            49 => style.bg = None,
+            38 => style.fg = extended_color(&mut params).or(style.fg),
+            48 => style.bg = extended_color(&mut params).or(style.bg),
+            // Underline color is not part of a style, but its arguments must be skipped
+            58 => {
+                extended_color(&mut params);
+            }
+            _ => {
+                if let Some(attr) = Attributes::from_sgr_param(&code.to_string()) {
+                    style.attrs |= attr;
+                }
+            }
+        }
+    }
+}
+
+/// Apply a colon-separated parameter such as `4:3` or `38:2::255:136:0`
+fn apply_subparams(style: &mut Style, param: &str) {
+    let mut parts = param.split(':');
+    let number = |part: Option<&str>| part.and_then(|part| part.parse::<u16>().ok());
+    match number(parts.next()) {
+        Some(4) => {
+            style.attrs -= UNDERLINES;
+            style.attrs |= match number(parts.next()) {
+                Some(0) => Attributes::empty(),
+                Some(2) => Attributes::DOUBLE_UNDERLINE,
+                Some(kind) => Attributes::from_sgr_param(&format!("4:{}", kind)).unwrap_or(Attributes::UNDERLINE),
+                None => Attributes::UNDERLINE,
+            };
+        }
+        Some(38) => style.fg = subparam_color(parts).or(style.fg),
+        Some(48) => style.bg = subparam_color(parts).or(style.bg),
+        _ => {}
+    }
+}
+
+/// Read the `5;n` or `2;r;g;b` arguments following `38` or `48`
+fn extended_color<'a>(params: &mut impl Iterator<Item = &'a str>) -> Option<Color> {
+    match params.next()? {
+        "5" => params.next()?.parse().ok().map(Color::Ansi256),
+        "2" => {
+            let r = params.next()?.parse().ok();
+            let g = params.next()?.parse().ok();
+            let b = params.next()?.parse().ok();
+            Some(Color::Rgb(r?, g?, b?))
+        }
+        _ => None,
+    }
+}
+
+/// Read the subparameters of `38:5:n`, `38:2:r:g:b` or `38:2:id:r:g:b`
+fn subparam_color<'a>(parts: impl Iterator<Item = &'a str>) -> Option<Color> {
+    let parts: Vec<&str> = parts.collect();
+    match parts.as_slice() {
+        ["5", n] => n.parse().ok().map(Color::Ansi256),
+        ["2", _, r, g, b] | ["2", r, g, b] => Some(Color::Rgb(r.parse().ok()?, g.parse().ok()?, b.parse().ok()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_codes() {
+        let parsed = parse("\x1b[1;3;91;44ma\x1b[22;23mb\x1b[4:3;38:2::1:2:3mc\x1b[24;39;49md");
+        assert_eq!(
+            parsed,
+            [
+                Span::new("a", Style::new().fg(Color::BrightRed).bg(Color::Blue).attrs(Attributes::BOLD | Attributes::ITALIC)),
+                Span::new("b", Style::new().fg(Color::BrightRed).bg(Color::Blue)),
+                Span::new("c", Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Blue).attrs(Attributes::CURLY_UNDERLINE)),
+                Span::new("d", Style::new()),
+            ]
+        );
+
+        // Zero-padded parameters, as written by `ls` and `grep`
+        let parsed = parse("\x1b[01;04;31mls\x1b[04:03mx\x1b[0m");
+        assert_eq!(parsed[0], Span::new("ls", Style::new().fg(Color::Red).attrs(Attributes::BOLD | Attributes::UNDERLINE)));
+        assert_eq!(parsed[1].style.attrs, Attributes::BOLD | Attributes::CURLY_UNDERLINE);
+
+        let attrs: Attributes = "bold dim rapid_blink reverse hidden strikethrough framed overline".parse().unwrap();
+        let parsed = parse(&format!("{}x", attrs.to_ansi()));
+        assert_eq!(parsed[0].style.attrs, attrs);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(parse("\x1b[31mred\x1b[38;5mstill\x1b[38;2;1;2m"), [Span::new("redstill", Color::Red.into())]);
+        assert_eq!(parse("\x1b[99;300;1mx\x1b[2Ky\x1b[0"), [Span::new("xy", Attributes::BOLD.into())]);
+        assert_eq!(parse("\x1b[1m\x1b[0mplain\x1b[32m"), [Span::new("plain", Style::new())]);
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        support::force_true_color();
+        let spans = vec![
+            Span::new("warn", Style::new().fg(Color::Yellow).attrs(Attributes::BOLD)),
+            Span::new(" ", Style::new()),
+            Span::new("", Style::new().fg(Color::Red)),
+            Span::new("x", Style::new().fg(Color::Ansi256(208)).bg(Color::Rgb(0, 0, 128))),
+        ];
+        let rendered = render(&spans);
+        assert_eq!(rendered, "\x1b[1;33mwarn\x1b[0m \x1b[38;5;208;48;2;0;0;128mx\x1b[0m");
+        assert_eq!(parse(&rendered), [spans[0].clone(), spans[1].clone(), spans[3].clone()]);
+        assert_eq!(render(&parse("\x1b[31ma\x1b[31mb\x1b[0m")), "\x1b[31mab\x1b[0m");
+        assert_eq!(render(&parse("\x1b[31ma\x1b[0m\x1b[1m\x1b[31mb\x1b[0m")), "\x1b[31ma\x1b[1mb\x1b[0m");
+    }
+}
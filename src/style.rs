@@ -0,0 +1,490 @@
+// File: src\style.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Style values and lazily formatted styled values for make_colors.
+// License: MIT
+
+//! Styles and styled values.
+//!
+//! A [`Style`] bundles a foreground, a background and [`Attributes`]. Painting
+//! a value with [`Style::paint`] returns a [`Styled`] wrapper that writes the
+//! escape codes straight into the formatter, so nothing is allocated and
+//! width, precision and alignment apply to the value itself.
+//!
+//! # Examples
+//! ```
+//! use make_colors::{Attributes, Color, Style};
+//!
+//! let style = Style::new().fg(Color::Green).attrs(Attributes::BOLD);
+//! println!("[{:>8}]", style.paint("ok"));
+//! println!("{:.2}", style.paint(3.14159));
+//!
+//! let warning: Style = "bold yellow on black".parse().unwrap();
+//! println!("{}", warning.paint("careful"));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::ansi::Segment;
+use crate::spans::{self, UNDERLINES};
+use crate::support::{self, ColorLevel, Stream};
+use crate::{ansi, quantize, Attributes, Color, MakeColorsError, Theme};
+
+/// Attribute groups and the SGR parameter that turns each group off
+const OFF_PARAMS: [(Attributes, &str); 9] = [
+    (Attributes::BOLD.union(Attributes::DIM), "22"),
+    (Attributes::ITALIC, "23"),
+    (UNDERLINES, "24"),
+    (Attributes::BLINK.union(Attributes::RAPID_BLINK), "25"),
+    (Attributes::REVERSE, "27"),
+    (Attributes::HIDDEN, "28"),
+    (Attributes::STRIKETHROUGH, "29"),
+    (Attributes::FRAMED.union(Attributes::ENCIRCLED), "54"),
+    (Attributes::OVERLINE, "55"),
+];
+
+/// Foreground, background and attributes applied to text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub attrs: Attributes,
+}
+
+impl Style {
+    /// A style without colors or attributes
+    pub const fn new() -> Self {
+        Style {
+            fg: None,
+            bg: None,
+            attrs: Attributes::empty(),
+        }
+    }
+
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Add attributes to the style
+    pub const fn attrs(mut self, attrs: Attributes) -> Self {
+        self.attrs = self.attrs.union(attrs);
+        self
+    }
+
+    /// Whether the style changes nothing
+    pub fn is_plain(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && self.attrs.is_empty()
+    }
+
+    /// Layer `other` on top of this style: its colors win and attributes add up
+    pub fn patch(self, other: Style) -> Self {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            attrs: self.attrs | other.attrs,
+        }
+    }
+
+    /// Wrap `value` so it is displayed with this style
+    pub fn paint<T>(self, value: T) -> Styled<T> {
+        Styled { value, style: self }
+    }
+
+    /// Write the escape codes that turn this style on, downsampled to `level`
+    pub fn write_prefix<W: fmt::Write>(&self, w: &mut W, level: ColorLevel) -> fmt::Result {
+        if !level.has_color() {
+            return Ok(());
+        }
+        for code in self.attrs.codes() {
+            w.write_str(code)?;
+        }
+        if let Some(fg) = self.fg {
+            quantize::downsample(fg, level).write_code(w, false)?;
+        }
+        if let Some(bg) = self.bg {
+            quantize::downsample(bg, level).write_code(w, true)?;
+        }
+        Ok(())
+    }
+
+    /// Write the reset that ends this style, if anything was turned on
+    pub fn write_suffix<W: fmt::Write>(&self, w: &mut W, level: ColorLevel) -> fmt::Result {
+        if level.has_color() && !self.is_plain() {
+            w.write_str(ansi::RESET)?;
+        }
+        Ok(())
+    }
+
+    /// Escape codes that turn this style on, downsampled to `level`
+    pub fn prefix(&self, level: ColorLevel) -> String {
+        let mut prefix = String::new();
+        let _ = self.write_prefix(&mut prefix, level);
+        prefix
+    }
+
+    /// Write the escape codes that switch from this style to `next`
+    ///
+    /// Only what differs is changed, in a single sequence: turning off bold
+    /// writes `22`, dropping the foreground writes `39`, and so on. Starting
+    /// from a plain style this is every code of `next`, such as `1;31` for
+    /// bold red. When resetting first is shorter, `0` followed by the codes of
+    /// `next` is written instead. Colors are compared after downsampling to
+    /// `level`, so nothing is written for changes `level` cannot show.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::{Attributes, Color, ColorLevel, Style};
+    ///
+    /// let bold_red = Style::new().fg(Color::Red).attrs(Attributes::BOLD);
+    /// let red = Style::new().fg(Color::Red);
+    /// assert_eq!(bold_red.transition(&red, ColorLevel::TrueColor), "\x1b[22m");
+    /// assert_eq!(bold_red.transition(&Style::new().attrs(Attributes::BOLD), ColorLevel::TrueColor), "\x1b[39m");
+    /// assert_eq!(red.transition(&Style::new().bg(Color::Blue), ColorLevel::TrueColor), "\x1b[0;44m");
+    /// assert_eq!(red.transition(&Style::new(), ColorLevel::TrueColor), "\x1b[0m");
+    /// assert_eq!(Style::new().transition(&bold_red, ColorLevel::TrueColor), "\x1b[1;31m");
+    /// ```
+    pub fn write_transition<W: fmt::Write>(&self, next: &Style, w: &mut W, level: ColorLevel) -> fmt::Result {
+        if !level.has_color() {
+            return Ok(());
+        }
+        let (from, to) = (self.downsample(level), next.downsample(level));
+        if from == to {
+            return Ok(());
+        }
+        if to.is_plain() {
+            return w.write_str(ansi::RESET);
+        }
+
+        let mut params = Vec::new();
+        let removed = from.attrs - to.attrs;
+        let mut added = to.attrs - from.attrs;
+        for (group, off) in OFF_PARAMS {
+            if removed.intersects(group) {
+                params.push(off.to_string());
+                // The off code clears the whole group, so restore what stays
+                added |= to.attrs & group;
+            }
+        }
+        params.extend(added.sgr_params().map(str::to_string));
+        if from.fg != to.fg {
+            params.push(to.fg.unwrap_or(Color::Default).sgr_params(false));
+        }
+        if from.bg != to.bg {
+            params.push(to.bg.unwrap_or(Color::Default).sgr_params(true));
+        }
+
+        let diff = format!("\x1b[{}m", params.join(";"));
+        if from.is_plain() {
+            return w.write_str(&diff);
+        }
+        let mut reset = vec!["0".to_string()];
+        reset.extend(to.attrs.sgr_params().map(str::to_string));
+        reset.extend(to.fg.map(|fg| fg.sgr_params(false)));
+        reset.extend(to.bg.map(|bg| bg.sgr_params(true)));
+        let reset = format!("\x1b[{}m", reset.join(";"));
+        w.write_str(if diff.len() <= reset.len() { &diff } else { &reset })
+    }
+
+    /// Escape codes that switch from this style to `next`, see [`Style::write_transition`]
+    pub fn transition(&self, next: &Style, level: ColorLevel) -> String {
+        let mut transition = String::new();
+        let _ = self.write_transition(next, &mut transition, level);
+        transition
+    }
+
+    /// Apply the style to `text`, which may already contain styled parts
+    ///
+    /// Styles inside `text` are layered on top of this one, and where they
+    /// end this style is restored instead of being reset, so colored pieces
+    /// can be nested. Escape sequences other than SGR are kept as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::{make_colors, support, Color, ColorLevel, Stream, Style};
+    ///
+    /// support::set_stream_color_level(Stream::Stdout, Some(ColorLevel::TrueColor));
+    /// let inner = make_colors("disk", "red", None);
+    /// let line = Style::new().fg(Color::Blue).nest(&format!("low {} space", inner));
+    /// assert_eq!(line, "\x1b[34mlow \x1b[31mdisk\x1b[34m space\x1b[0m");
+    /// ```
+    pub fn nest(&self, text: &str) -> String {
+        self.nest_at(text, support::color_level(Stream::Stdout))
+    }
+
+    fn nest_at(&self, text: &str, level: ColorLevel) -> String {
+        let mut result = String::new();
+        let mut inner = Style::new();
+        let mut active = Style::new();
+
+        for segment in ansi::segments(text) {
+            match segment {
+                Segment::Escape(escape) => match ansi::sgr_params(escape) {
+                    Some(params) => spans::apply_sgr(&mut inner, params),
+                    None => result.push_str(escape),
+                },
+                Segment::Text(text) => {
+                    let style = self.patch(inner);
+                    let _ = active.write_transition(&style, &mut result, level);
+                    active = style;
+                    result.push_str(text);
+                }
+            }
+        }
+        let _ = active.write_transition(&Style::new(), &mut result, level);
+
+        result
+    }
+
+    /// Apply the style to `text`, ending with a reset
+    ///
+    /// Text that already contains escape codes is nested, see [`Style::nest`].
+    pub(crate) fn render(&self, text: &str, level: ColorLevel) -> String {
+        if text.contains('\x1b') {
+            return self.nest_at(text, level);
+        }
+        let mut result = self.prefix(level);
+        result.push_str(text);
+        let _ = self.write_suffix(&mut result, level);
+        result
+    }
+
+    /// The style with its colors downsampled to `level`
+    fn downsample(&self, level: ColorLevel) -> Style {
+        Style {
+            fg: self.fg.map(|fg| quantize::downsample(fg, level)),
+            bg: self.bg.map(|bg| quantize::downsample(bg, level)),
+            attrs: self.attrs,
+        }
+    }
+
+    /// Parse style words, reporting the byte offset and reason of a failure
+    ///
+    /// Words that are not attributes or colors are looked up as roles in
+    /// `theme`, when one is given.
+    pub(crate) fn parse_words(s: &str, theme: Option<&Theme>) -> Result<Style, (usize, String)> {
+        let mut style = Style::new();
+        let mut words = split_words(s).into_iter();
+
+        while let Some((pos, word)) = words.next() {
+            if word.eq_ignore_ascii_case("on") {
+                let (pos, bg) = words
+                    .next()
+                    .ok_or_else(|| (pos, "expected a background color after 'on'".to_string()))?;
+                let color = bg
+                    .parse()
+                    .map_err(|_| (pos, format!("unknown background color '{}'", bg)))?;
+                style.bg = Some(color);
+            } else if word.eq_ignore_ascii_case("none") {
+                continue;
+            } else if let Some(attr) = Attributes::from_name(word) {
+                style.attrs |= attr;
+            } else if let Ok(color) = word.parse() {
+                style.fg = Some(color);
+            } else if let Some(role) = theme.and_then(|theme| theme.get(word)) {
+                style = style.patch(role);
+            } else {
+                return Err((pos, format!("unknown style '{}'", word)));
+            }
+        }
+
+        Ok(style)
+    }
+}
+
+/// Split style words on whitespace, keeping `rgb(...)` arguments together
+fn split_words(s: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s_start) = start.take() {
+                    words.push((s_start, &s[s_start..i]));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s_start) = start {
+        words.push((s_start, &s[s_start..]));
+    }
+
+    words
+}
+
+impl FromStr for Style {
+    type Err = MakeColorsError;
+
+    /// Parse a style such as `"bold red on white"` or `"italic #FF8800"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Style::parse_words(s, None).map_err(|(_, message)| MakeColorsError::InvalidStyle(message))
+    }
+}
+
+impl fmt::Display for Style {
+    /// Write the style in the syntax accepted by `FromStr`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_plain() {
+            return f.write_str("none");
+        }
+
+        let mut words = Vec::new();
+        if !self.attrs.is_empty() {
+            words.push(self.attrs.to_string());
+        }
+        if let Some(fg) = self.fg {
+            words.push(fg.to_string());
+        }
+        if let Some(bg) = self.bg {
+            words.push(format!("on {}", bg));
+        }
+        f.write_str(&words.join(" "))
+    }
+}
+
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        Style::new().fg(color)
+    }
+}
+
+impl From<Attributes> for Style {
+    fn from(attrs: Attributes) -> Self {
+        Style::new().attrs(attrs)
+    }
+}
+
+/// A value displayed with a [`Style`]
+///
+/// Every formatting trait of the inner value is forwarded, with the
+/// formatter's width, precision and alignment applied to the value only.
+/// Escape codes are written according to the color support of stdout.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Styled<T> {
+    pub value: T,
+    pub style: Style,
+}
+
+impl<T> Styled<T> {
+    pub fn new(value: T, style: Style) -> Self {
+        Styled { value, style }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Replace the style
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+macro_rules! forward_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl<T: fmt::$trait> fmt::$trait for Styled<T> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let level = support::color_level(Stream::Stdout);
+                    self.style.write_prefix(f, level)?;
+                    fmt::$trait::fmt(&self.value, f)?;
+                    self.style.write_suffix(f, level)
+                }
+            }
+        )*
+    };
+}
+
+forward_fmt!(Display, Debug, LowerHex, UpperHex, Octal, Binary, LowerExp, UpperExp);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styled_formatting() {
+        support::force_true_color();
+        let style = Style::new().fg(Color::Red).attrs(Attributes::BOLD);
+        assert_eq!(format!("{}", style.paint("hi")), "\x1b[1m\x1b[31mhi\x1b[0m");
+        assert_eq!(format!("{:>4}", style.paint("hi")), "\x1b[1m\x1b[31m  hi\x1b[0m");
+        assert_eq!(format!("{:.1}", style.paint(1.25)), "\x1b[1m\x1b[31m1.2\x1b[0m");
+        assert_eq!(format!("{:#x}", style.paint(255)), "\x1b[1m\x1b[31m0xff\x1b[0m");
+        assert_eq!(format!("{:?}", Style::new().paint("q")), "\"q\"");
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let style: Style = "bold underline red on rgb(0, 0, 128)".parse().unwrap();
+        assert_eq!(style.fg, Some(Color::Red));
+        assert_eq!(style.bg, Some(Color::Rgb(0, 0, 128)));
+        assert_eq!(style.attrs, Attributes::BOLD | Attributes::UNDERLINE);
+        assert_eq!(style.to_string(), "bold underline red on #000080");
+        assert_eq!(style.to_string().parse::<Style>().unwrap(), style);
+        assert_eq!("none".parse::<Style>().unwrap(), Style::new());
+        assert!(matches!("bold on".parse::<Style>(), Err(MakeColorsError::InvalidStyle(_))));
+    }
+
+    #[test]
+    fn test_patch_and_levels() {
+        let base = Style::new().fg(Color::Red).bg(Color::White);
+        let patched = base.patch(Style::new().fg(Color::Blue).attrs(Attributes::ITALIC));
+        assert_eq!(patched, Style::new().fg(Color::Blue).bg(Color::White).attrs(Attributes::ITALIC));
+
+        let orange = Style::new().fg(Color::Rgb(255, 136, 0));
+        assert_eq!(orange.render("x", ColorLevel::Ansi256), "\x1b[38;5;208mx\x1b[0m");
+        assert_eq!(orange.render("x", ColorLevel::None), "x");
+        assert_eq!(Style::new().render("x", ColorLevel::TrueColor), "x");
+    }
+
+    #[test]
+    fn test_transitions() {
+        let level = ColorLevel::TrueColor;
+        let red = Style::new().fg(Color::Red);
+        let bold_dim = Style::new().attrs(Attributes::BOLD | Attributes::DIM);
+        assert_eq!(red.attrs(bold_dim.attrs).transition(&red.attrs(Attributes::DIM), level), "\x1b[22;2m");
+        assert_eq!(red.transition(&red.attrs(Attributes::CURLY_UNDERLINE), level), "\x1b[4:3m");
+        assert_eq!(red.bg(Color::White).transition(&red, level), "\x1b[49m");
+        assert_eq!(red.transition(&Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::BrightBlue), level), "\x1b[38;2;1;2;3;104m");
+        assert_eq!(Style::new().transition(&red.attrs(Attributes::BOLD), level), "\x1b[1;31m");
+        assert_eq!(red.transition(&red, level), "");
+
+        // A reset is shorter than turning four attributes off
+        let busy: Style = "bold italic underline strike red".parse().unwrap();
+        assert_eq!(busy.transition(&Style::new().fg(Color::Blue), level), "\x1b[0;34m");
+
+        // Colors that look the same at the level need no codes
+        let orange = Style::new().fg(Color::Rgb(255, 136, 0));
+        assert_eq!(orange.transition(&Style::new().fg(Color::Rgb(255, 135, 0)), ColorLevel::Ansi256), "");
+        assert_eq!(orange.transition(&Style::new(), ColorLevel::None), "");
+    }
+
+    #[test]
+    fn test_nesting() {
+        let level = ColorLevel::TrueColor;
+        let inner = Style::new().attrs(Attributes::BOLD).render("b", level);
+        let text = format!("a{}\x1b]8;;https://x\x07c", inner);
+        assert_eq!(
+            Style::new().fg(Color::Red).render(&text, level),
+            "\x1b[31ma\x1b[1mb\x1b]8;;https://x\x07\x1b[22mc\x1b[0m"
+        );
+        let plain = Style::new().render(&format!("{}{}", inner, inner), level);
+        assert_eq!(plain, "\x1b[1mbb\x1b[0m");
+        assert_eq!(Style::new().fg(Color::Red).render(&inner, ColorLevel::None), "b");
+    }
+}
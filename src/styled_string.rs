@@ -0,0 +1,408 @@
+// File: src\styled_string.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: A text buffer made of style runs, editable by visible column.
+// License: MIT
+
+//! Styled text buffers.
+//!
+//! A [`StyledString`] keeps text together with its style runs, so colored
+//! lines can be built piece by piece, cut, padded and highlighted without
+//! flattening them into escape codes and parsing them back. Column-based
+//! operations ([`slice`](StyledString::slice),
+//! [`highlight_range`](StyledString::highlight_range),
+//! [`pad`](StyledString::pad)) count terminal columns of whole graphemes,
+//! like [`ansi::visible_width`](crate::ansi::visible_width), and are meant
+//! for single lines; use [`split_lines`](StyledString::split_lines) first
+//! for longer text.
+//!
+//! # Examples
+//! ```
+//! use make_colors::styled_string::{Align, StyledString};
+//! use make_colors::{Color, ColorLevel, Style};
+//!
+//! let mut line = StyledString::new();
+//! line.push_styled("ERROR", "bold red".parse().unwrap());
+//! line.push_str(" disk /dev/sda1 is full");
+//! line.highlight_range(11..20, Style::new().fg(Color::Cyan));
+//!
+//! let cell = line.slice(..16).pad(20, Align::Left);
+//! assert_eq!(cell.text(), "ERROR disk /dev/    ");
+//! println!("{}", cell.render(ColorLevel::TrueColor));
+//! ```
+
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use crate::spans::{self, Span};
+use crate::support::{self, ColorLevel, Stream};
+use crate::{width, Style};
+
+/// Where [`StyledString::pad`] puts the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// Text with style runs
+///
+/// Runs are never empty and neighbouring runs always differ in style, so
+/// two strings that look the same compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StyledString {
+    spans: Vec<Span>,
+}
+
+impl StyledString {
+    pub fn new() -> Self {
+        StyledString { spans: Vec::new() }
+    }
+
+    /// A string with a single style
+    pub fn styled(text: &str, style: Style) -> Self {
+        let mut string = StyledString::new();
+        string.push_styled(text, style);
+        string
+    }
+
+    /// Parse text containing escape codes, see [`spans::parse`]
+    pub fn from_ansi(text: &str) -> Self {
+        spans::parse(text).into_iter().collect()
+    }
+
+    /// The style runs
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Append unstyled text
+    pub fn push_str(&mut self, text: &str) {
+        self.push_styled(text, Style::new());
+    }
+
+    /// Append text with a style
+    pub fn push_styled(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(text),
+            _ => self.spans.push(Span::new(text, style)),
+        }
+    }
+
+    /// Append another styled string
+    pub fn append(&mut self, other: &StyledString) {
+        for span in &other.spans {
+            self.push_styled(&span.text, span.style);
+        }
+    }
+
+    /// The text without styles
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Terminal columns taken by the text
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(|span| width::text_width(&span.text)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The graphemes within a range of columns
+    ///
+    /// Wide characters that only partly fall inside the range are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::styled_string::StyledString;
+    ///
+    /// let text = StyledString::from("日本語 text");
+    /// assert_eq!(text.slice(1..5).text(), "本");
+    /// assert_eq!(text.slice(7..).text(), "text");
+    /// ```
+    pub fn slice(&self, columns: impl RangeBounds<usize>) -> StyledString {
+        let (start, end) = column_bounds(columns);
+        let mut slice = StyledString::new();
+        for (column, grapheme, style) in self.cells() {
+            let width = width::grapheme_width(grapheme).max(1);
+            if column >= start && column + width <= end {
+                slice.push_styled(grapheme, style);
+            }
+        }
+        slice
+    }
+
+    /// Split at line breaks (`\n` or `\r\n`), like [`str::lines`]
+    pub fn split_lines(&self) -> Vec<StyledString> {
+        let mut lines = vec![StyledString::new()];
+        for span in &self.spans {
+            for (i, part) in span.text.split('\n').enumerate() {
+                if i > 0 {
+                    lines.push(StyledString::new());
+                }
+                if let Some(line) = lines.last_mut() {
+                    line.push_styled(part, span.style);
+                }
+            }
+        }
+
+        if lines.last().is_some_and(StyledString::is_empty) {
+            lines.pop();
+        }
+        for line in &mut lines {
+            if let Some(last) = line.spans.last_mut() {
+                if last.text.ends_with('\r') {
+                    last.text.pop();
+                    if last.text.is_empty() {
+                        line.spans.pop();
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// Replace every occurrence of `from` in the text with `to`
+    ///
+    /// Matches may cross style runs; the replacement takes the style of
+    /// the first character it replaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::styled_string::StyledString;
+    /// use make_colors::{Color, Style};
+    ///
+    /// let mut text = StyledString::from("status: ");
+    /// text.push_styled("FAIL", Style::new().fg(Color::Red));
+    /// let text = text.replace("FAIL", "failed");
+    /// assert_eq!(text.text(), "status: failed");
+    /// assert_eq!(text.spans()[1].style.fg, Some(Color::Red));
+    /// ```
+    pub fn replace(&self, from: &str, to: &str) -> StyledString {
+        if from.is_empty() {
+            return self.clone();
+        }
+
+        let text = self.text();
+        let mut result = StyledString::new();
+        let mut position = 0;
+        for (start, _) in text.match_indices(from) {
+            self.push_bytes(&mut result, position, start);
+            result.push_styled(to, self.style_at(start));
+            position = start + from.len();
+        }
+        self.push_bytes(&mut result, position, text.len());
+        result
+    }
+
+    /// Layer `style` over a range of columns
+    ///
+    /// Colors of `style` replace the existing ones and attributes are added,
+    /// as with [`Style::patch`].
+    pub fn highlight_range(&mut self, columns: impl RangeBounds<usize>, style: Style) {
+        let (start, end) = column_bounds(columns);
+        let mut highlighted = StyledString::new();
+        for (column, grapheme, existing) in self.cells() {
+            let inside = column >= start && column < end;
+            highlighted.push_styled(grapheme, if inside { existing.patch(style) } else { existing });
+        }
+        *self = highlighted;
+    }
+
+    /// Pad with unstyled spaces to at least `width` columns
+    pub fn pad(&self, width: usize, align: Align) -> StyledString {
+        let missing = width.saturating_sub(self.width());
+        let (before, after) = match align {
+            Align::Left => (0, missing),
+            Align::Right => (missing, 0),
+            Align::Center => (missing / 2, missing - missing / 2),
+        };
+
+        let mut padded = StyledString::new();
+        padded.push_str(&" ".repeat(before));
+        padded.append(self);
+        padded.push_str(&" ".repeat(after));
+        padded
+    }
+
+    /// Escape codes and text for a terminal with `level` color support
+    pub fn render(&self, level: ColorLevel) -> String {
+        spans::render_at(&self.spans, level)
+    }
+
+    /// Graphemes with their starting column and style
+    fn cells(&self) -> impl Iterator<Item = (usize, &str, Style)> + '_ {
+        let mut column = 0;
+        self.spans
+            .iter()
+            .flat_map(|span| width::graphemes(&span.text).map(move |grapheme| (grapheme, span.style)))
+            .map(move |(grapheme, style)| {
+                let start = column;
+                column += width::grapheme_width(grapheme);
+                (start, grapheme, style)
+            })
+    }
+
+    /// Style of the text at byte offset `index`
+    fn style_at(&self, index: usize) -> Style {
+        let mut offset = 0;
+        for span in &self.spans {
+            offset += span.text.len();
+            if index < offset {
+                return span.style;
+            }
+        }
+        Style::new()
+    }
+
+    /// Copy the styled text between two byte offsets into `target`
+    fn push_bytes(&self, target: &mut StyledString, start: usize, end: usize) {
+        let mut offset = 0;
+        for span in &self.spans {
+            let (span_start, span_end) = (offset, offset + span.text.len());
+            offset = span_end;
+            let (from, to) = (start.max(span_start), end.min(span_end));
+            if from < to {
+                target.push_styled(&span.text[from - span_start..to - span_start], span.style);
+            }
+        }
+    }
+}
+
+/// Start and exclusive end of a column range
+fn column_bounds(columns: impl RangeBounds<usize>) -> (usize, usize) {
+    let start = match columns.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match columns.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+    (start, end)
+}
+
+impl fmt::Display for StyledString {
+    /// Render for the color support of stdout
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render(support::color_level(Stream::Stdout)))
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(text: &str) -> Self {
+        StyledString::styled(text, Style::new())
+    }
+}
+
+impl From<String> for StyledString {
+    fn from(text: String) -> Self {
+        StyledString::from(text.as_str())
+    }
+}
+
+impl From<Span> for StyledString {
+    fn from(span: Span) -> Self {
+        StyledString::styled(&span.text, span.style)
+    }
+}
+
+impl FromIterator<Span> for StyledString {
+    fn from_iter<I: IntoIterator<Item = Span>>(iter: I) -> Self {
+        let mut string = StyledString::new();
+        for span in iter {
+            string.push_styled(&span.text, span.style);
+        }
+        string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Attributes, Color};
+
+    fn sample() -> StyledString {
+        let mut string = StyledString::new();
+        string.push_styled("ab", Color::Red.into());
+        string.push_styled("日", Color::Blue.into());
+        string.push_str("cd");
+        string
+    }
+
+    #[test]
+    fn test_building() {
+        let mut string = sample();
+        string.push_str("e");
+        string.append(&StyledString::styled("f", Color::Red.into()));
+        assert_eq!(string.text(), "ab日cdef");
+        assert_eq!(string.width(), 8);
+        assert_eq!(string.spans().len(), 4);
+        assert!(StyledString::styled("", Color::Red.into()).is_empty());
+
+        let rendered = string.render(ColorLevel::TrueColor);
+        assert_eq!(rendered, "\x1b[31mab\x1b[34m日\x1b[0mcde\x1b[31mf\x1b[0m");
+        assert_eq!(StyledString::from_ansi(&rendered), string);
+        assert_eq!(string.render(ColorLevel::None), "ab日cdef");
+    }
+
+    #[test]
+    fn test_columns() {
+        let string = sample();
+        assert_eq!(string.slice(1..4), {
+            let mut expected = StyledString::styled("b", Color::Red.into());
+            expected.push_styled("日", Color::Blue.into());
+            expected
+        });
+        assert_eq!(string.slice(3..).text(), "cd");
+        assert_eq!(string.slice(..=2).text(), "ab");
+
+        let mut highlighted = string.clone();
+        highlighted.highlight_range(1..5, Style::new().attrs(Attributes::BOLD).bg(Color::White));
+        let styles: Vec<_> = highlighted.spans().iter().map(|span| (span.text.as_str(), span.style.to_string())).collect();
+        assert_eq!(
+            styles,
+            [
+                ("a", "red".to_string()),
+                ("b", "bold red on white".to_string()),
+                ("日", "bold blue on white".to_string()),
+                ("c", "bold on white".to_string()),
+                ("d", "none".to_string()),
+            ]
+        );
+
+        assert_eq!(string.pad(9, Align::Right).text(), "   ab日cd");
+        assert_eq!(string.pad(9, Align::Center).text(), " ab日cd  ");
+        assert_eq!(string.pad(3, Align::Left), string);
+    }
+
+    #[test]
+    fn test_lines_and_replace() {
+        let mut string = StyledString::styled("one\r\ntw", Color::Green.into());
+        string.push_str("o\n\nthree\n");
+        let lines = string.split_lines();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], StyledString::styled("one", Color::Green.into()));
+        assert_eq!(lines[1].spans().len(), 2);
+        assert!(lines[2].is_empty());
+        assert_eq!(lines[3].text(), "three");
+        assert!(StyledString::new().split_lines().is_empty());
+
+        let replaced = sample().replace("b日c", "-").replace("a", "A");
+        assert_eq!(replaced, {
+            let mut expected = StyledString::styled("A-", Color::Red.into());
+            expected.push_str("d");
+            expected
+        });
+        assert_eq!(sample().replace("", "x"), sample());
+    }
+}
@@ -0,0 +1,267 @@
+// File: src\support.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Terminal color support detection for make_colors.
+// License: MIT
+
+//! Color support detection.
+//!
+//! The colorizing functions only emit escape codes when the output stream
+//! supports them. Support is detected once per stream from the environment
+//! and can be overridden globally or per stream:
+//!
+//! 1. `FORCE_COLOR` - `0`/`false` disables colors, `1`/`2`/`3` (or empty/`true`)
+//!    force 16, 256 or true color
+//! 2. `NO_COLOR` - any non-empty value disables colors
+//! 3. `CLICOLOR_FORCE` - any value other than `0` forces colors
+//! 4. `CLICOLOR=0`, a stream that is not a terminal or `TERM=dumb` disable colors
+//! 5. `COLORTERM=truecolor`/`24bit`, `TERM=*-256color` and friends pick the level
+//!
+//! # Examples
+//! ```
+//! use make_colors::support::{self, ColorLevel, Stream};
+//!
+//! // Always emit colors, e.g. when writing a colored log on purpose
+//! support::set_color_level(Some(ColorLevel::TrueColor));
+//! assert_eq!(support::color_level(Stream::Stdout), ColorLevel::TrueColor);
+//!
+//! // Back to automatic detection
+//! support::set_color_level(None);
+//! ```
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// How many colors a stream can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorLevel {
+    /// No escape codes at all
+    None,
+    /// The 16 standard ANSI colors
+    Ansi16,
+    /// The xterm 256-color palette
+    Ansi256,
+    /// 24-bit true color
+    TrueColor,
+}
+
+impl ColorLevel {
+    /// Whether any escape codes should be emitted
+    pub fn has_color(&self) -> bool {
+        *self != ColorLevel::None
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            ColorLevel::None => 1,
+            ColorLevel::Ansi16 => 2,
+            ColorLevel::Ansi256 => 3,
+            ColorLevel::TrueColor => 4,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(ColorLevel::None),
+            2 => Some(ColorLevel::Ansi16),
+            3 => Some(ColorLevel::Ansi256),
+            4 => Some(ColorLevel::TrueColor),
+            _ => None,
+        }
+    }
+}
+
+/// An output stream colors are written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    /// Whether the stream is attached to a terminal
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Stream::Stdout => io::stdout().is_terminal(),
+            Stream::Stderr => io::stderr().is_terminal(),
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Stream::Stdout => 0,
+            Stream::Stderr => 1,
+        }
+    }
+}
+
+/// Snapshot of the environment variables that decide color support
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColorSupport {
+    force_color: Option<String>,
+    no_color: Option<String>,
+    clicolor_force: Option<String>,
+    clicolor: Option<String>,
+    term: Option<String>,
+    colorterm: Option<String>,
+    wt_session: Option<String>,
+}
+
+impl ColorSupport {
+    /// Read the current process environment
+    pub fn from_env() -> Self {
+        Self::from_lookup(|name| env::var(name).ok())
+    }
+
+    /// Read variables through `lookup`, e.g. from a saved environment
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::support::{ColorLevel, ColorSupport};
+    ///
+    /// let support = ColorSupport::from_lookup(|name| match name {
+    ///     "TERM" => Some("xterm-256color".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(support.level(true), ColorLevel::Ansi256);
+    /// assert_eq!(support.level(false), ColorLevel::None);
+    /// ```
+    pub fn from_lookup<F: Fn(&str) -> Option<String>>(lookup: F) -> Self {
+        Self {
+            force_color: lookup("FORCE_COLOR"),
+            no_color: lookup("NO_COLOR"),
+            clicolor_force: lookup("CLICOLOR_FORCE"),
+            clicolor: lookup("CLICOLOR"),
+            term: lookup("TERM"),
+            colorterm: lookup("COLORTERM"),
+            wt_session: lookup("WT_SESSION"),
+        }
+    }
+
+    /// Color level for a stream, `is_terminal` telling whether it is a TTY
+    pub fn level(&self, is_terminal: bool) -> ColorLevel {
+        if let Some(force) = &self.force_color {
+            let forced = match force.trim().to_lowercase().as_str() {
+                "0" | "false" => return ColorLevel::None,
+                "2" => ColorLevel::Ansi256,
+                "3" => ColorLevel::TrueColor,
+                _ => ColorLevel::Ansi16,
+            };
+            return forced.max(self.term_level());
+        }
+
+        if self.no_color.as_deref().is_some_and(|v| !v.is_empty()) {
+            return ColorLevel::None;
+        }
+
+        if self.clicolor_force.as_deref().is_some_and(|v| !v.is_empty() && v != "0") {
+            return self.term_level().max(ColorLevel::Ansi16);
+        }
+
+        if self.clicolor.as_deref() == Some("0") || !is_terminal {
+            return ColorLevel::None;
+        }
+
+        self.term_level()
+    }
+
+    /// Level advertised by `TERM` and `COLORTERM` alone
+    fn term_level(&self) -> ColorLevel {
+        let colorterm = self.colorterm.as_deref().unwrap_or("").to_lowercase();
+        let term = self.term.as_deref().unwrap_or("").to_lowercase();
+
+        if term == "dumb" {
+            ColorLevel::None
+        } else if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.ends_with("-direct")
+            || term.contains("truecolor")
+        {
+            ColorLevel::TrueColor
+        } else if term.contains("256") {
+            ColorLevel::Ansi256
+        } else if term.is_empty() && cfg!(windows) && self.wt_session.is_some() {
+            ColorLevel::TrueColor
+        } else {
+            ColorLevel::Ansi16
+        }
+    }
+}
+
+/// Detect the color level of a stream from the environment, ignoring overrides
+pub fn detect(stream: Stream) -> ColorLevel {
+    ColorSupport::from_env().level(stream.is_terminal())
+}
+
+static OVERRIDES: [AtomicU8; 2] = [AtomicU8::new(0), AtomicU8::new(0)];
+static DETECTED: [OnceLock<ColorLevel>; 2] = [OnceLock::new(), OnceLock::new()];
+
+/// Color level used when writing to `stream`
+///
+/// Returns the override set with [`set_color_level`] or
+/// [`set_stream_color_level`], otherwise the level detected on first use.
+pub fn color_level(stream: Stream) -> ColorLevel {
+    let index = stream.index();
+    ColorLevel::from_u8(OVERRIDES[index].load(Ordering::Relaxed))
+        .unwrap_or_else(|| *DETECTED[index].get_or_init(|| detect(stream)))
+}
+
+/// Override the color level of every stream, `None` restores detection
+pub fn set_color_level(level: Option<ColorLevel>) {
+    set_stream_color_level(Stream::Stdout, level);
+    set_stream_color_level(Stream::Stderr, level);
+}
+
+/// Override the color level of one stream, `None` restores detection
+pub fn set_stream_color_level(stream: Stream, level: Option<ColorLevel>) {
+    let value = level.map_or(0, ColorLevel::to_u8);
+    OVERRIDES[stream.index()].store(value, Ordering::Relaxed);
+}
+
+/// Force true color on stdout, for tests that assert escape codes
+///
+/// Every test that renders to stdout uses this same level, so tests running
+/// in parallel never see a different one.
+#[cfg(test)]
+pub(crate) fn force_true_color() {
+    set_stream_color_level(Stream::Stdout, Some(ColorLevel::TrueColor));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn support(vars: &[(&str, &str)]) -> ColorSupport {
+        ColorSupport::from_lookup(|name| {
+            vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn test_terminal_levels() {
+        assert_eq!(support(&[("TERM", "xterm")]).level(true), ColorLevel::Ansi16);
+        assert_eq!(support(&[("TERM", "screen-256color")]).level(true), ColorLevel::Ansi256);
+        assert_eq!(support(&[("TERM", "xterm"), ("COLORTERM", "truecolor")]).level(true), ColorLevel::TrueColor);
+        assert_eq!(support(&[("TERM", "dumb"), ("COLORTERM", "truecolor")]).level(true), ColorLevel::None);
+        assert_eq!(support(&[("TERM", "xterm-256color")]).level(false), ColorLevel::None);
+    }
+
+    #[test]
+    fn test_disabling_variables() {
+        assert_eq!(support(&[("TERM", "xterm"), ("NO_COLOR", "1")]).level(true), ColorLevel::None);
+        assert_eq!(support(&[("TERM", "xterm"), ("NO_COLOR", "")]).level(true), ColorLevel::Ansi16);
+        assert_eq!(support(&[("TERM", "xterm"), ("CLICOLOR", "0")]).level(true), ColorLevel::None);
+        assert_eq!(support(&[("TERM", "xterm"), ("FORCE_COLOR", "0")]).level(true), ColorLevel::None);
+    }
+
+    #[test]
+    fn test_forcing_variables() {
+        assert_eq!(support(&[("FORCE_COLOR", "")]).level(false), ColorLevel::Ansi16);
+        assert_eq!(support(&[("FORCE_COLOR", "3"), ("NO_COLOR", "1")]).level(false), ColorLevel::TrueColor);
+        assert_eq!(support(&[("FORCE_COLOR", "1"), ("TERM", "xterm-256color")]).level(false), ColorLevel::Ansi256);
+        assert_eq!(support(&[("CLICOLOR_FORCE", "1")]).level(false), ColorLevel::Ansi16);
+        assert_eq!(support(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]).level(false), ColorLevel::None);
+    }
+}
@@ -0,0 +1,902 @@
+// File: src\syntax.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Tokenizers and theme-colored rendering of source code snippets.
+// License: MIT
+
+//! Syntax highlighting (`syntax` feature).
+//!
+//! [`tokenize`] splits source code in one of the supported [`Language`]s
+//! into [`Token`]s, and [`highlight`] turns them into a [`StyledString`],
+//! optionally with line numbers, a range of lines and marked lines, ready
+//! to be rendered at any [`ColorLevel`](crate::ColorLevel). The tokenizers
+//! are small hand-written lexers meant for snippets in error reports and
+//! help output: they need no grammar files and never fail, but they do not
+//! parse the language.
+//!
+//! Token styles come from the theme: a role such as `syntax.keyword` or
+//! `syntax.string` is used if the theme defines it, and otherwise the
+//! closest standard role (`highlight` for keywords, `success` for strings,
+//! `muted` for comments, ...), so every built-in theme has matching code
+//! colors.
+//!
+//! # Examples
+//! ```
+//! use make_colors::syntax::{self, Language, SyntaxOptions};
+//! use make_colors::ColorLevel;
+//!
+//! let source = "fn main() {\n    let answer = 42;\n    println!(\"{}\", answer);\n}\n";
+//! let options = SyntaxOptions::default().line_numbers().lines(2..=3).highlight_line(2);
+//! let code = syntax::highlight(source, Language::Rust, &options);
+//! println!("{}", code);
+//! println!("{}", code.render(ColorLevel::Ansi256));
+//!
+//! let language: Language = "config.yml".parse().unwrap();
+//! println!("{}", syntax::highlight("name: demo\nreplicas: 3\n", language, &SyntaxOptions::default()));
+//! ```
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::styled_string::Align;
+use crate::theme::{self, Theme};
+use crate::{Color, MakeColorsError, Style, StyledString};
+
+/// A language with a tokenizer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Rust,
+    Json,
+    Toml,
+    Yaml,
+    Shell,
+    Sql,
+    Python,
+}
+
+impl Language {
+    pub const ALL: [Language; 7] = [
+        Language::Rust,
+        Language::Json,
+        Language::Toml,
+        Language::Yaml,
+        Language::Shell,
+        Language::Sql,
+        Language::Python,
+    ];
+
+    /// A language by name or file extension, case-insensitively
+    pub fn from_name(name: &str) -> Option<Language> {
+        let name = name.trim().trim_start_matches('.').to_lowercase();
+        Some(match name.as_str() {
+            "rust" | "rs" => Language::Rust,
+            "json" | "jsonc" | "geojson" => Language::Json,
+            "toml" => Language::Toml,
+            "yaml" | "yml" => Language::Yaml,
+            "shell" | "sh" | "bash" | "zsh" | "ksh" | "console" => Language::Shell,
+            "sql" => Language::Sql,
+            "python" | "py" | "pyi" | "python3" => Language::Python,
+            _ => return None,
+        })
+    }
+
+    /// The language of a file, from its extension
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::syntax::Language;
+    ///
+    /// assert_eq!(Language::from_path("src/main.rs"), Some(Language::Rust));
+    /// assert_eq!(Language::from_path("Cargo.toml"), Some(Language::Toml));
+    /// assert_eq!(Language::from_path("README.md"), None);
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Language> {
+        path.as_ref().extension().and_then(|e| e.to_str()).and_then(Language::from_name)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Json => "json",
+            Language::Toml => "toml",
+            Language::Yaml => "yaml",
+            Language::Shell => "shell",
+            Language::Sql => "sql",
+            Language::Python => "python",
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = MakeColorsError;
+
+    /// Parse a language name, file extension or file name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::from_name(s)
+            .or_else(|| Language::from_path(s))
+            .ok_or_else(|| MakeColorsError::InvalidLanguage(s.to_string()))
+    }
+}
+
+/// What a token is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// Whitespace, plain identifiers and anything else without a style
+    Text,
+    Keyword,
+    /// Type names and built-in types
+    Type,
+    /// Function and macro names, and shell built-ins
+    Function,
+    String,
+    Number,
+    /// `true`, `false`, `null` and constants written in capitals
+    Constant,
+    Comment,
+    /// Keys of JSON objects, TOML tables and YAML mappings
+    Key,
+    /// Variables and named references such as `$HOME`, `&anchor` or `'a`
+    Variable,
+    /// Rust attributes and Python decorators
+    Attribute,
+    Operator,
+    Punctuation,
+}
+
+impl TokenKind {
+    /// Theme role looked up first for this kind, such as `syntax.keyword`
+    pub fn role(self) -> &'static str {
+        match self {
+            TokenKind::Text => "syntax.text",
+            TokenKind::Keyword => "syntax.keyword",
+            TokenKind::Type => "syntax.type",
+            TokenKind::Function => "syntax.function",
+            TokenKind::String => "syntax.string",
+            TokenKind::Number => "syntax.number",
+            TokenKind::Constant => "syntax.constant",
+            TokenKind::Comment => "syntax.comment",
+            TokenKind::Key => "syntax.key",
+            TokenKind::Variable => "syntax.variable",
+            TokenKind::Attribute => "syntax.attribute",
+            TokenKind::Operator => "syntax.operator",
+            TokenKind::Punctuation => "syntax.punctuation",
+        }
+    }
+
+    /// Standard role used when the theme has no `syntax.*` role
+    fn fallback_role(self) -> Option<&'static str> {
+        match self {
+            TokenKind::Keyword => Some("highlight"),
+            TokenKind::Type | TokenKind::Key => Some("info"),
+            TokenKind::Function | TokenKind::Variable => Some("debug"),
+            TokenKind::String => Some("success"),
+            TokenKind::Number | TokenKind::Constant | TokenKind::Attribute => Some("warning"),
+            TokenKind::Comment => Some("muted"),
+            TokenKind::Text | TokenKind::Operator | TokenKind::Punctuation => None,
+        }
+    }
+}
+
+/// A run of source code of one kind
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Byte range in the source
+    pub range: Range<usize>,
+}
+
+/// Options for [`highlight`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyntaxOptions {
+    /// Show line numbers in a gutter
+    pub line_numbers: bool,
+    /// 1-based, inclusive range of lines to show; all lines when `None`
+    pub lines: Option<RangeInclusive<usize>>,
+    /// 1-based numbers of lines to mark and give a background
+    pub highlight_lines: Vec<usize>,
+    /// Theme for the token styles; the current theme when `None`
+    pub theme: Option<Theme>,
+}
+
+impl SyntaxOptions {
+    pub fn line_numbers(mut self) -> Self {
+        self.line_numbers = true;
+        self
+    }
+
+    pub fn lines(mut self, lines: RangeInclusive<usize>) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    pub fn highlight_line(mut self, line: usize) -> Self {
+        self.highlight_lines.push(line);
+        self
+    }
+
+    pub fn highlight_lines(mut self, lines: impl IntoIterator<Item = usize>) -> Self {
+        self.highlight_lines.extend(lines);
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+/// Style of a token kind in `theme`
+pub fn token_style(kind: TokenKind, theme: &Theme) -> Style {
+    theme
+        .get(kind.role())
+        .or_else(|| kind.fallback_role().and_then(|role| theme.get(role)))
+        .unwrap_or_default()
+}
+
+/// Highlight source code
+///
+/// Lines are separated by `\n` in the result, without a trailing newline.
+/// Marked lines are prefixed with `❱` and padded to the widest shown line
+/// so their background (`syntax.highlighted_line`, dark gray by default)
+/// forms a bar. Line numbers use the `syntax.line_number` role, or `muted`.
+pub fn highlight(source: &str, language: Language, options: &SyntaxOptions) -> StyledString {
+    let current;
+    let theme = match &options.theme {
+        Some(theme) => theme,
+        None => {
+            current = theme::read_current();
+            &current
+        }
+    };
+
+    let mut code = StyledString::new();
+    for token in tokenize(source, language) {
+        code.push_styled(&source[token.range], token_style(token.kind, theme));
+    }
+    let lines = code.split_lines();
+
+    let first = options.lines.as_ref().map_or(1, |lines| (*lines.start()).max(1));
+    let last = options.lines.as_ref().map_or(lines.len(), |range| (*range.end()).min(lines.len()));
+    if first > last {
+        return StyledString::new();
+    }
+    let shown = &lines[first - 1..last];
+
+    let role = |role: &str, fallback: &str| theme.get(role).or_else(|| theme.get(fallback)).unwrap_or_default();
+    let gutter = role("syntax.line_number", "muted");
+    let marker = role("syntax.marker", "highlight");
+    let bar = theme.get("syntax.highlighted_line").unwrap_or(Style::new().bg(Color::Rgb(58, 58, 58)));
+    let number_width = last.to_string().len();
+    let code_width = shown.iter().map(StyledString::width).max().unwrap_or(0);
+
+    let mut output = StyledString::new();
+    for (index, line) in shown.iter().enumerate() {
+        let number = first + index;
+        let marked = options.highlight_lines.contains(&number);
+        if index > 0 {
+            output.push_str("\n");
+        }
+
+        if !options.highlight_lines.is_empty() {
+            match marked {
+                true => output.push_styled("❱", marker),
+                false => output.push_str(" "),
+            }
+            output.push_str(" ");
+        }
+        if options.line_numbers {
+            output.push_styled(&format!("{:>width$} │ ", number, width = number_width), gutter);
+        }
+        if marked {
+            let mut line = line.pad(code_width, Align::Left);
+            line.highlight_range(.., bar);
+            output.append(&line);
+        } else {
+            output.append(line);
+        }
+    }
+    output
+}
+
+/// Split source code into tokens
+///
+/// The tokens cover the whole source, in order, and neighbouring tokens
+/// differ in kind.
+///
+/// # Examples
+/// ```
+/// use make_colors::syntax::{tokenize, Language, TokenKind};
+///
+/// let source = r#"{"port": 8080, "debug": false}"#;
+/// let kinds: Vec<_> = tokenize(source, Language::Json)
+///     .into_iter()
+///     .filter(|token| token.kind != TokenKind::Text)
+///     .map(|token| (token.kind, &source[token.range]))
+///     .collect();
+/// assert_eq!(kinds[1], (TokenKind::Key, "\"port\""));
+/// assert_eq!(kinds[3], (TokenKind::Number, "8080"));
+/// assert_eq!(kinds[7], (TokenKind::Constant, "false"));
+/// ```
+pub fn tokenize(source: &str, language: Language) -> Vec<Token> {
+    let mut lexer = Lexer {
+        source,
+        language,
+        grammar: grammar(language),
+        tokens: Vec::new(),
+        position: 0,
+        previous_word: "",
+    };
+    lexer.run();
+    lexer.tokens
+}
+
+/// Word lists and delimiters of a language
+struct Grammar {
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    constants: &'static [&'static str],
+    builtins: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    /// String delimiters, longest first
+    quotes: &'static [&'static str],
+    /// Whether strings with single-character delimiters may span lines
+    multiline_strings: bool,
+    case_insensitive: bool,
+}
+
+fn grammar(language: Language) -> Grammar {
+    let base = Grammar {
+        keywords: &[],
+        types: &[],
+        constants: &["true", "false"],
+        builtins: &[],
+        line_comment: None,
+        block_comment: None,
+        quotes: &["\""],
+        multiline_strings: false,
+        case_insensitive: false,
+    };
+
+    match language {
+        Language::Rust => Grammar {
+            keywords: &[
+                "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "fn", "for",
+                "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static",
+                "struct", "super", "trait", "type", "union", "unsafe", "use", "where", "while", "yield",
+            ],
+            types: &[
+                "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+                "isize", "f32", "f64",
+            ],
+            line_comment: Some("//"),
+            block_comment: Some(("/*", "*/")),
+            multiline_strings: true,
+            ..base
+        },
+        Language::Json => Grammar {
+            constants: &["true", "false", "null"],
+            ..base
+        },
+        Language::Toml => Grammar {
+            constants: &["true", "false", "inf", "nan"],
+            line_comment: Some("#"),
+            quotes: &["\"\"\"", "'''", "\"", "'"],
+            ..base
+        },
+        Language::Yaml => Grammar {
+            constants: &[
+                "true", "True", "TRUE", "false", "False", "FALSE", "null", "Null", "NULL", "yes", "Yes", "no", "No", "on",
+                "On", "off", "Off",
+            ],
+            line_comment: Some("#"),
+            quotes: &["\"", "'"],
+            ..base
+        },
+        Language::Shell => Grammar {
+            keywords: &[
+                "if", "then", "else", "elif", "fi", "case", "esac", "for", "while", "until", "do", "done", "in", "function",
+                "select", "time", "return", "exit", "export", "local", "readonly", "declare", "unset",
+            ],
+            builtins: &[
+                "echo", "cd", "printf", "read", "source", "test", "eval", "exec", "set", "shift", "trap", "alias", "pwd",
+                "wait", "kill",
+            ],
+            line_comment: Some("#"),
+            quotes: &["\"", "'", "`"],
+            multiline_strings: true,
+            ..base
+        },
+        Language::Sql => Grammar {
+            keywords: &[
+                "select", "from", "where", "and", "or", "not", "insert", "into", "values", "update", "set", "delete",
+                "create", "table", "drop", "alter", "add", "column", "index", "on", "join", "left", "right", "inner",
+                "outer", "full", "cross", "as", "order", "by", "group", "having", "limit", "offset", "distinct", "union",
+                "all", "in", "is", "like", "between", "case", "when", "then", "else", "end", "primary", "key", "foreign",
+                "references", "default", "unique", "exists", "begin", "commit", "rollback", "returning", "with", "view",
+                "if", "asc", "desc",
+            ],
+            types: &[
+                "int", "integer", "bigint", "smallint", "text", "varchar", "char", "boolean", "bool", "date", "time",
+                "timestamp", "float", "real", "double", "numeric", "decimal", "serial", "blob", "json", "uuid",
+            ],
+            constants: &["null", "true", "false"],
+            line_comment: Some("--"),
+            block_comment: Some(("/*", "*/")),
+            quotes: &["'", "\""],
+            case_insensitive: true,
+            ..base
+        },
+        Language::Python => Grammar {
+            keywords: &[
+                "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+                "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or",
+                "pass", "raise", "return", "try", "while", "with", "yield", "match", "case",
+            ],
+            types: &["int", "float", "str", "bool", "bytes", "list", "dict", "set", "tuple", "object", "type"],
+            constants: &["True", "False", "None"],
+            line_comment: Some("#"),
+            quotes: &["\"\"\"", "'''", "\"", "'"],
+            ..base
+        },
+    }
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    language: Language,
+    grammar: Grammar,
+    tokens: Vec<Token>,
+    position: usize,
+    /// Last keyword or identifier, to name what `fn`, `def` or `class` defines
+    previous_word: &'a str,
+}
+
+impl<'a> Lexer<'a> {
+    fn run(&mut self) {
+        while let Some(c) = self.source[self.position..].chars().next() {
+            let start = self.position;
+            let (kind, end) = if c.is_whitespace() {
+                (TokenKind::Text, self.scan_while(start, char::is_whitespace))
+            } else {
+                self.comment(start)
+                    .or_else(|| self.special(start, c))
+                    .or_else(|| self.string(start, start))
+                    .or_else(|| self.number(start, c))
+                    .or_else(|| self.word(start, c))
+                    .unwrap_or_else(|| self.symbol(start, c))
+            };
+            self.push(kind, end);
+        }
+    }
+
+    fn push(&mut self, kind: TokenKind, end: usize) {
+        let end = end.max(self.position + 1).min(self.source.len());
+        let end = (end..=self.source.len()).find(|&i| self.source.is_char_boundary(i)).unwrap_or(end);
+        match self.tokens.last_mut() {
+            Some(last) if last.kind == kind => last.range.end = end,
+            _ => self.tokens.push(Token {
+                kind,
+                range: self.position..end,
+            }),
+        }
+        self.position = end;
+    }
+
+    fn rest(&self, from: usize) -> &'a str {
+        &self.source[from..]
+    }
+
+    /// End of the run of characters accepted by `accept` starting at `from`
+    fn scan_while(&self, from: usize, accept: impl Fn(char) -> bool) -> usize {
+        self.rest(from).find(|c: char| !accept(c)).map_or(self.source.len(), |i| from + i)
+    }
+
+    fn line_end(&self, from: usize) -> usize {
+        self.rest(from).find('\n').map_or(self.source.len(), |i| from + i)
+    }
+
+    /// Whether only whitespace precedes `at` on its line
+    fn at_line_start(&self, at: usize) -> bool {
+        self.source[..at].rsplit('\n').next().is_some_and(|line| line.trim().is_empty())
+    }
+
+    /// Whether `at` starts a shell or YAML word
+    fn at_word_start(&self, at: usize) -> bool {
+        self.source[..at].chars().next_back().is_none_or(|c| c.is_whitespace() || c == ';')
+    }
+
+    fn comment(&self, start: usize) -> Option<(TokenKind, usize)> {
+        let rest = self.rest(start);
+        if let Some((open, close)) = self.grammar.block_comment {
+            if let Some(body) = rest.strip_prefix(open) {
+                let end = body.find(close).map_or(self.source.len(), |i| start + open.len() + i + close.len());
+                return Some((TokenKind::Comment, end));
+            }
+        }
+
+        let marker = self.grammar.line_comment?;
+        let word_only = matches!(self.language, Language::Shell | Language::Yaml);
+        (rest.starts_with(marker) && (!word_only || self.at_word_start(start))).then(|| (TokenKind::Comment, self.line_end(start)))
+    }
+
+    /// Tokens particular to one language
+    fn special(&self, start: usize, c: char) -> Option<(TokenKind, usize)> {
+        let rest = self.rest(start);
+        let identifier = |c: char| c.is_alphanumeric() || c == '_';
+        match (self.language, c) {
+            (Language::Rust, '#') if rest.starts_with("#[") || rest.starts_with("#![") => {
+                let mut depth = 0;
+                for (i, c) in rest.char_indices() {
+                    match c {
+                        '[' => depth += 1,
+                        ']' if depth == 1 => return Some((TokenKind::Attribute, start + i + 1)),
+                        ']' => depth -= 1,
+                        _ => {}
+                    }
+                }
+                Some((TokenKind::Attribute, self.source.len()))
+            }
+            (Language::Rust, '\'') => {
+                let mut chars = rest[1..].char_indices();
+                match chars.next() {
+                    // '\n', '\u{1F600}'
+                    Some((_, '\\')) => {
+                        // Skip the escaped character, which may itself be a quote
+                        let body = 2 + chars.next().map_or(0, |(_, c)| c.len_utf8());
+                        rest.get(body..)?.find('\'').map(|i| (TokenKind::String, start + body + i + 1))
+                    }
+                    Some((_, c)) if rest[1 + c.len_utf8()..].starts_with('\'') => {
+                        Some((TokenKind::String, start + 2 + c.len_utf8()))
+                    }
+                    // Lifetimes and labels
+                    Some((_, c)) if identifier(c) => Some((TokenKind::Variable, self.scan_while(start + 1, identifier))),
+                    _ => None,
+                }
+            }
+            (Language::Python, '@') if self.at_line_start(start) => {
+                Some((TokenKind::Attribute, self.scan_while(start + 1, |c| identifier(c) || c == '.')))
+            }
+            (Language::Shell, '$') => match rest[1..].chars().next() {
+                Some('{') => Some((TokenKind::Variable, rest.find('}').map_or(self.line_end(start), |i| start + i + 1))),
+                Some(c) if identifier(c) => Some((TokenKind::Variable, self.scan_while(start + 1, identifier))),
+                Some(c @ ('?' | '#' | '@' | '*' | '!' | '$' | '-')) => Some((TokenKind::Variable, start + 1 + c.len_utf8())),
+                _ => None,
+            },
+            (Language::Toml, '[') if self.at_line_start(start) => {
+                let end = self.line_end(start);
+                let header = self.source[start..end].rfind(']').map_or(end, |i| start + i + 1);
+                Some((TokenKind::Key, header))
+            }
+            (Language::Yaml, '&' | '*') if self.at_word_start(start) && rest[1..].starts_with(identifier) => {
+                Some((TokenKind::Variable, self.scan_while(start + 1, |c| identifier(c) || c == '-')))
+            }
+            _ => None,
+        }
+    }
+
+    /// A string whose opening quote is at `quote`, and whose token starts
+    /// at `start` to include prefixes such as `b` or `f`
+    fn string(&self, start: usize, quote: usize) -> Option<(TokenKind, usize)> {
+        let rest = self.rest(quote);
+        let delimiter = *self.grammar.quotes.iter().find(|q| rest.starts_with(**q))?;
+        let escapes = !(self.language == Language::Toml && delimiter.starts_with('\''));
+        let multiline = delimiter.len() == 3 || self.grammar.multiline_strings;
+
+        let mut end = self.source.len();
+        let mut chars = rest[delimiter.len()..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            let at = quote + delimiter.len() + i;
+            if c == '\\' && escapes {
+                chars.next();
+            } else if c == '\n' && !multiline {
+                end = at;
+                break;
+            } else if self.source[at..].starts_with(delimiter) {
+                end = at + delimiter.len();
+                break;
+            }
+        }
+        Some((self.string_kind(start, end), end))
+    }
+
+    /// Whether a string is used as a key
+    fn string_kind(&self, start: usize, end: usize) -> TokenKind {
+        let after = self.rest(end).trim_start_matches([' ', '\t']);
+        let key = match self.language {
+            Language::Json | Language::Yaml => after.starts_with(':'),
+            Language::Toml => self.at_line_start(start) && (after.starts_with('=') || after.starts_with('.')),
+            _ => false,
+        };
+        if key {
+            TokenKind::Key
+        } else {
+            TokenKind::String
+        }
+    }
+
+    fn number(&self, start: usize, c: char) -> Option<(TokenKind, usize)> {
+        let rest = self.rest(start);
+        let leading_dot = c == '.'
+            && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+            && !self.source[..start].ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == ')');
+        if !c.is_ascii_digit() && !leading_dot {
+            return None;
+        }
+
+        let hex = rest.starts_with("0x") || rest.starts_with("0X");
+        let mut end = start;
+        let mut previous = '\0';
+        for c in rest.chars() {
+            let accepted = match c {
+                '.' => !self.rest(end + 1).starts_with(|c: char| c == '.' || c.is_alphabetic()),
+                '+' | '-' => !hex && matches!(previous, 'e' | 'E'),
+                c => c.is_alphanumeric() || c == '_',
+            };
+            if !accepted {
+                break;
+            }
+            previous = c;
+            end += c.len_utf8();
+        }
+        Some((TokenKind::Number, end))
+    }
+
+    fn word(&mut self, start: usize, c: char) -> Option<(TokenKind, usize)> {
+        if !(c.is_alphabetic() || c == '_') {
+            return None;
+        }
+        let dashes = matches!(self.language, Language::Toml | Language::Yaml | Language::Shell);
+        let end = self.scan_while(start, |c| c.is_alphanumeric() || c == '_' || (dashes && c == '-'));
+        let word = &self.source[start..end];
+        let after = self.rest(end);
+
+        // String prefixes: b"..", r#".."#, f'..'
+        let prefixes: &[&str] = match self.language {
+            Language::Rust => &["b", "r", "br"],
+            Language::Python => &["r", "b", "f", "u", "rb", "br", "fr", "rf"],
+            _ => &[],
+        };
+        if prefixes.iter().any(|p| p.eq_ignore_ascii_case(word)) {
+            if self.language == Language::Rust && word.ends_with('r') && after.starts_with(['#', '"']) {
+                let hashes = after.len() - after.trim_start_matches('#').len();
+                if after[hashes..].starts_with('"') {
+                    let close = format!("\"{}", "#".repeat(hashes));
+                    let body = end + hashes + 1;
+                    let end = self.rest(body).find(&close).map_or(self.source.len(), |i| body + i + close.len());
+                    return Some((TokenKind::String, end));
+                }
+            } else if self.language == Language::Rust && word == "b" && after.starts_with('\'') {
+                return self.special(end, '\'').map(|(_, end)| (TokenKind::String, end));
+            } else if let Some(string) = self.string(start, end) {
+                return Some(string);
+            }
+        }
+
+        let grammar = &self.grammar;
+        let listed = |list: &[&str]| match grammar.case_insensitive {
+            true => list.iter().any(|w| w.eq_ignore_ascii_case(word)),
+            false => list.contains(&word),
+        };
+        let defines = |keywords: &[&str]| keywords.contains(&self.previous_word);
+        let capitalized = word.starts_with(|c: char| c.is_uppercase());
+        let shouting = word.len() > 1 && word.chars().all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_');
+        let code = matches!(self.language, Language::Rust | Language::Python);
+
+        let (kind, end) = if listed(grammar.keywords) {
+            (TokenKind::Keyword, end)
+        } else if listed(grammar.constants) {
+            (TokenKind::Constant, end)
+        } else if listed(grammar.types) {
+            (TokenKind::Type, end)
+        } else if self.language == Language::Rust && after.starts_with('!') && after[1..].starts_with(['(', '[', '{']) {
+            (TokenKind::Function, end + 1)
+        } else if after.starts_with('(') || listed(grammar.builtins) || (code && defines(&["fn", "def"])) {
+            (TokenKind::Function, end)
+        } else if code && shouting {
+            (TokenKind::Constant, end)
+        } else if code && (capitalized || defines(&["struct", "enum", "trait", "union", "class"])) {
+            (TokenKind::Type, end)
+        } else if self.is_key(start, after) {
+            (TokenKind::Key, end)
+        } else {
+            (TokenKind::Text, end)
+        };
+
+        self.previous_word = word;
+        Some((kind, end))
+    }
+
+    /// Whether a bare word is a YAML or TOML key
+    fn is_key(&self, start: usize, after: &str) -> bool {
+        match self.language {
+            Language::Yaml => after.starts_with(':') && after[1..].chars().next().is_none_or(char::is_whitespace),
+            Language::Toml => {
+                let line_start = self.source[..start].rfind('\n').map_or(0, |i| i + 1);
+                let before = &self.source[line_start..start];
+                let after = after.trim_start_matches([' ', '\t']);
+                before.chars().all(|c| c.is_alphanumeric() || " \t._-\"'".contains(c))
+                    && (after.starts_with('.') || (after.starts_with('=') && !after.starts_with("==")))
+            }
+            _ => false,
+        }
+    }
+
+    fn symbol(&self, start: usize, c: char) -> (TokenKind, usize) {
+        const OPERATORS: &str = "+-*/%=<>!&|^~?:@$";
+        const PUNCTUATION: &str = "(){}[],;.";
+        if OPERATORS.contains(c) {
+            (TokenKind::Operator, self.scan_while(start, |c| OPERATORS.contains(c)))
+        } else if PUNCTUATION.contains(c) {
+            (TokenKind::Punctuation, start + 1)
+        } else {
+            (TokenKind::Text, start + c.len_utf8())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Attributes;
+
+    fn tokens(source: &str, language: Language) -> Vec<(TokenKind, &str)> {
+        tokenize(source, language)
+            .into_iter()
+            .filter(|token| !matches!(token.kind, TokenKind::Text | TokenKind::Punctuation))
+            .map(|token| (token.kind, &source[token.range]))
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        use TokenKind::*;
+
+        let rust = "#[derive(Debug)]\nstruct Point<'a> { x: f64 } // note\nfn main() { let s = r#\"a \"b\"\"#; println!(\"{}\", '\\n', '\\''); }";
+        assert_eq!(
+            tokens(rust, Language::Rust),
+            [
+                (Attribute, "#[derive(Debug)]"),
+                (Keyword, "struct"),
+                (Type, "Point"),
+                (Operator, "<"),
+                (Variable, "'a"),
+                (Operator, ">"),
+                (Operator, ":"),
+                (Type, "f64"),
+                (Comment, "// note"),
+                (Keyword, "fn"),
+                (Function, "main"),
+                (Keyword, "let"),
+                (Operator, "="),
+                (String, "r#\"a \"b\"\"#"),
+                (Function, "println!"),
+                (String, "\"{}\""),
+                (String, "'\\n'"),
+                (String, "'\\''"),
+            ]
+        );
+
+        let python = "@cached\ndef load(path=None):\n    return f\"{path}\" if MAX_SIZE > 1.5e3 else 0x1F  # done";
+        assert_eq!(
+            tokens(python, Language::Python),
+            [
+                (Attribute, "@cached"),
+                (Keyword, "def"),
+                (Function, "load"),
+                (Operator, "="),
+                (Constant, "None"),
+                (Operator, ":"),
+                (Keyword, "return"),
+                (String, "f\"{path}\""),
+                (Keyword, "if"),
+                (Constant, "MAX_SIZE"),
+                (Operator, ">"),
+                (Number, "1.5e3"),
+                (Keyword, "else"),
+                (Number, "0x1F"),
+                (Comment, "# done"),
+            ]
+        );
+
+        let toml = "[package]\nname = \"demo\" # the name\nfeatures.default = ['a']\n";
+        assert_eq!(
+            tokens(toml, Language::Toml),
+            [
+                (Key, "[package]"),
+                (Key, "name"),
+                (Operator, "="),
+                (String, "\"demo\""),
+                (Comment, "# the name"),
+                (Key, "features"),
+                (Key, "default"),
+                (Operator, "="),
+                (String, "'a'"),
+            ]
+        );
+
+        let yaml = "base: &base\n  retries: 3 # max\n  url: \"http://x#y\"\nlive: yes\n";
+        let found = tokens(yaml, Language::Yaml);
+        assert_eq!(found[0], (Key, "base"));
+        assert_eq!(found[2], (Variable, "&base"));
+        assert_eq!(found[5], (Number, "3"));
+        assert_eq!(found[6], (Comment, "# max"));
+        assert_eq!(found[9], (String, "\"http://x#y\""));
+        assert_eq!(found[12], (Constant, "yes"));
+
+        let shell = "if [ -n \"$HOME\" ]; then echo ${USER} $1 # hi\nfi";
+        assert_eq!(
+            tokens(shell, Language::Shell),
+            [
+                (Keyword, "if"),
+                (Operator, "-"),
+                (String, "\"$HOME\""),
+                (Keyword, "then"),
+                (Function, "echo"),
+                (Variable, "${USER}"),
+                (Variable, "$1"),
+                (Comment, "# hi"),
+                (Keyword, "fi"),
+            ]
+        );
+
+        let sql = "SELECT count(*) FROM users WHERE name = 'it''s' -- all\n/* x */ LIMIT 10";
+        let found = tokens(sql, Language::Sql);
+        assert_eq!(found[..3], [(Keyword, "SELECT"), (Function, "count"), (Operator, "*")]);
+        assert!(found.contains(&(String, "'it''s'")));
+        assert!(found.contains(&(Comment, "-- all")));
+        assert!(found.ends_with(&[(Comment, "/* x */"), (Keyword, "LIMIT"), (Number, "10")]));
+
+        // Tokens cover the source, even when it is malformed
+        for language in Language::ALL {
+            for source in [rust, python, toml, yaml, shell, sql, "\"unterminated\n'日本", "", "r#\"x"] {
+                let tokens = tokenize(source, language);
+                let covered: std::string::String = tokens.iter().map(|token| &source[token.range.clone()]).collect();
+                assert_eq!(covered, source, "{}", language);
+            }
+        }
+    }
+
+    #[test]
+    fn test_languages() {
+        assert_eq!("YML".parse::<Language>().unwrap(), Language::Yaml);
+        assert_eq!("deploy.sh".parse::<Language>().unwrap(), Language::Shell);
+        assert_eq!(Language::from_name(".py"), Some(Language::Python));
+        assert!(matches!("cobol".parse::<Language>(), Err(MakeColorsError::InvalidLanguage(_))));
+        for language in Language::ALL {
+            assert_eq!(Language::from_name(language.name()), Some(language));
+        }
+    }
+
+    #[test]
+    fn test_highlight() {
+        let theme = Theme::new("test")
+            .with("muted", Style::new().attrs(Attributes::DIM))
+            .with("syntax.keyword", Style::new().fg(Color::Magenta))
+            .with("highlight", Style::new().fg(Color::Red));
+        let source = "let a = 1;\nlet bb = 22;\nlet ccc = 333;\n";
+
+        let options = SyntaxOptions::default().theme(theme.clone()).line_numbers().lines(2..=9).highlight_line(3);
+        let code = highlight(source, Language::Rust, &options);
+        assert_eq!(code.text(), "  2 │ let bb = 22;\n❱ 3 │ let ccc = 333;");
+        let spans = code.spans();
+        assert_eq!(spans[0].style, Style::new());
+        assert_eq!(spans[1], crate::Span::new("2 │ ", Style::new().attrs(Attributes::DIM)));
+        assert_eq!(spans[2], crate::Span::new("let", Style::new().fg(Color::Magenta)));
+        assert_eq!(spans[4], crate::Span::new("❱", Style::new().fg(Color::Red)));
+        assert!(spans.last().unwrap().style.bg.is_some());
+
+        let plain = SyntaxOptions::default().theme(theme);
+        assert_eq!(highlight(source, Language::Rust, &plain).text(), source.trim_end());
+        assert!(highlight(source, Language::Rust, &plain.lines(5..=6)).is_empty());
+        assert!(highlight("", Language::Json, &SyntaxOptions::default()).is_empty());
+    }
+}
@@ -0,0 +1,328 @@
+// File: src\theme.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Named color themes mapping semantic roles to styles.
+// License: MIT
+
+//! Themes with semantic roles.
+//!
+//! A [`Theme`] maps role names such as `error`, `warning`, `success`, `info`
+//! and `muted` to [`Style`]s, so call sites say what a piece of text *is*
+//! instead of which color it should have. The process-wide current theme is
+//! used by markup tags (`[error]...[/]`) and [`ColorBuilder::role`], and can
+//! be swapped at runtime with [`set_current`].
+//!
+//! [`ColorBuilder::role`]: crate::ColorBuilder::role
+//!
+//! # Examples
+//! ```
+//! use make_colors::theme::{self, Theme};
+//! use make_colors::{markup, ColorBuilder, Style};
+//!
+//! println!("{}", markup::render("[error]failed[/] after [muted]3 retries[/]").unwrap());
+//! println!("{}", ColorBuilder::new("saved").role("success").build());
+//!
+//! theme::set_current(Theme::builtin("dracula").unwrap());
+//! let custom = Theme::new("mine").with("error", "bold #FF0000".parse::<Style>().unwrap());
+//! theme::set_current(custom);
+//! # theme::set_current(Theme::default());
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::{OnceLock, RwLock, RwLockReadGuard};
+
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use crate::MakeColorsError;
+use crate::Style;
+
+/// Roles defined by every built-in theme
+pub const ROLES: [&str; 7] = ["error", "warning", "success", "info", "debug", "muted", "highlight"];
+
+/// Names of the built-in themes
+pub const BUILTIN_THEMES: [&str; 6] = ["default", "solarized", "dracula", "monokai", "high-contrast", "okabe-ito"];
+
+/// A named mapping from semantic roles to styles
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "crate::serde_impl::ThemeData", into = "crate::serde_impl::ThemeData")
+)]
+pub struct Theme {
+    name: String,
+    styles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// An empty theme
+    pub fn new(name: &str) -> Self {
+        Theme {
+            name: name.to_string(),
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// A built-in theme by name (see [`BUILTIN_THEMES`])
+    ///
+    /// Names are case-insensitive and `_` may be used instead of `-`.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase().replace('_', "-");
+        let styles: [&str; 7] = match name.as_str() {
+            "default" => [
+                "bold lightred",
+                "yellow",
+                "green",
+                "cyan",
+                "blue",
+                "lightblack",
+                "bold magenta",
+            ],
+            "solarized" => [
+                "bold #DC322F",
+                "#B58900",
+                "#859900",
+                "#268BD2",
+                "#2AA198",
+                "#586E75",
+                "bold #D33682",
+            ],
+            "dracula" => [
+                "bold #FF5555",
+                "#FFB86C",
+                "#50FA7B",
+                "#8BE9FD",
+                "#BD93F9",
+                "#6272A4",
+                "bold #FF79C6",
+            ],
+            "monokai" => [
+                "bold #F92672",
+                "#FD971F",
+                "#A6E22E",
+                "#66D9EF",
+                "#AE81FF",
+                "#75715E",
+                "bold #E6DB74",
+            ],
+            "high-contrast" => [
+                "bold lightwhite on red",
+                "bold black on lightyellow",
+                "bold black on lightgreen",
+                "bold lightwhite on blue",
+                "lightwhite",
+                "white",
+                "bold underline lightyellow",
+            ],
+            // Color-blind safe, see crate::vision::OKABE_ITO
+            "okabe-ito" => [
+                "bold #D55E00",
+                "#E69F00",
+                "#009E73",
+                "#56B4E9",
+                "#0072B2",
+                "dim",
+                "bold #F0E442",
+            ],
+            _ => return None,
+        };
+
+        Some(ROLES.iter().zip(styles).fold(Theme::new(&name), |theme, (role, style)| {
+            theme.with(role, style.parse().expect("built-in theme styles are valid"))
+        }))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Set the style of `role`, replacing any previous one
+    pub fn with(mut self, role: &str, style: Style) -> Self {
+        self.insert(role, style);
+        self
+    }
+
+    /// Set the style of `role`, returning the previous one
+    pub fn insert(&mut self, role: &str, style: Style) -> Option<Style> {
+        self.styles.insert(role.trim().to_lowercase(), style)
+    }
+
+    pub fn remove(&mut self, role: &str) -> Option<Style> {
+        self.styles.remove(&role.trim().to_lowercase())
+    }
+
+    /// Style of `role`; role names are case-insensitive
+    pub fn get(&self, role: &str) -> Option<Style> {
+        self.styles.get(&role.trim().to_lowercase()).copied()
+    }
+
+    /// Roles and their styles, sorted by role name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.styles.iter().map(|(role, style)| (role.as_str(), *style))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Theme {
+    /// Parse a theme from TOML
+    ///
+    /// The file has an optional `name` and a `[roles]` table of style
+    /// strings. Invalid entries are reported with their line and column.
+    ///
+    /// # Examples
+    /// ```
+    /// use make_colors::{MakeColorsError, Theme};
+    ///
+    /// let theme = Theme::from_toml("name = \"ops\"\n[roles]\nerror = \"bold #FF0000\"\n").unwrap();
+    /// assert!(theme.get("error").is_some());
+    ///
+    /// let err = Theme::from_toml("[roles]\nerror = \"bold purpel\"\n").unwrap_err();
+    /// assert!(matches!(err, MakeColorsError::InvalidTheme { line: 2, column: 9, .. }));
+    /// ```
+    pub fn from_toml(source: &str) -> Result<Theme, MakeColorsError> {
+        toml::from_str(source).map_err(|err| {
+            let (line, column) = err.span().map_or((1, 1), |span| line_column(source, span.start));
+            MakeColorsError::InvalidTheme {
+                line,
+                column,
+                message: err.message().to_string(),
+            }
+        })
+    }
+
+    /// Parse a theme from JSON: `{"name": "...", "roles": {"error": "bold red"}}`
+    pub fn from_json(source: &str) -> Result<Theme, MakeColorsError> {
+        serde_json::from_str(source).map_err(|err| {
+            let message = err.to_string();
+            let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(message, _)| message);
+            MakeColorsError::InvalidTheme {
+                line: err.line(),
+                column: err.column(),
+                message: message.to_string(),
+            }
+        })
+    }
+
+    /// Load a `.toml` or `.json` theme file
+    ///
+    /// A theme without a `name` is named after the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Theme, MakeColorsError> {
+        let path = path.as_ref();
+        let file_error = |message: String| MakeColorsError::ThemeFile {
+            path: path.display().to_string(),
+            message,
+        };
+
+        let source = std::fs::read_to_string(path).map_err(|err| file_error(err.to_string()))?;
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        let mut theme = match extension.to_lowercase().as_str() {
+            "toml" => Theme::from_toml(&source)?,
+            "json" => Theme::from_json(&source)?,
+            _ => return Err(file_error("expected a .toml or .json file".to_string())),
+        };
+
+        if theme.name.is_empty() {
+            theme.name = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+        }
+        Ok(theme)
+    }
+}
+
+/// 1-based line and column of a byte offset
+#[cfg(feature = "serde")]
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::builtin("default").expect("the default theme exists")
+    }
+}
+
+fn current_lock() -> &'static RwLock<Theme> {
+    static CURRENT: OnceLock<RwLock<Theme>> = OnceLock::new();
+    CURRENT.get_or_init(|| RwLock::new(Theme::default()))
+}
+
+/// A copy of the process-wide current theme
+pub fn current() -> Theme {
+    read_current().clone()
+}
+
+/// Borrow the process-wide current theme without copying it
+///
+/// Writers wait until the guard is dropped, so do not call back into this
+/// module while holding it.
+pub(crate) fn read_current() -> RwLockReadGuard<'static, Theme> {
+    current_lock().read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Replace the process-wide current theme
+pub fn set_current(theme: Theme) {
+    *current_lock().write().unwrap_or_else(|e| e.into_inner()) = theme;
+}
+
+/// Style of `role` in the current theme
+pub fn role_style(role: &str) -> Option<Style> {
+    read_current().get(role)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Attributes, Color};
+
+    #[test]
+    fn test_builtin_themes() {
+        for name in BUILTIN_THEMES {
+            let theme = Theme::builtin(name).unwrap();
+            assert_eq!(theme.name(), name);
+            assert!(ROLES.iter().all(|role| theme.get(role).is_some()));
+        }
+        assert_eq!(Theme::builtin("High_Contrast").unwrap().name(), "high-contrast");
+        assert!(Theme::builtin("nope").is_none());
+        assert_eq!(
+            Theme::builtin("dracula").unwrap().get("ERROR"),
+            Some(Style::new().fg(Color::Rgb(255, 85, 85)).attrs(Attributes::BOLD))
+        );
+    }
+
+    #[test]
+    fn test_custom_theme() {
+        let mut theme = Theme::new("custom").with("Alert", Style::new().fg(Color::Red));
+        assert_eq!(theme.get("alert"), Some(Style::new().fg(Color::Red)));
+        assert_eq!(theme.insert("alert", Style::new()), Some(Style::new().fg(Color::Red)));
+        assert_eq!(theme.iter().map(|(role, _)| role).collect::<Vec<_>>(), ["alert"]);
+        assert!(theme.remove("alert").is_some());
+        assert!(theme.get("alert").is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_theme_files() {
+        let dir = std::env::temp_dir().join(format!("make_colors_theme_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let json = dir.join("night.json");
+        std::fs::write(&json, "{\n  \"roles\": {\n    \"info\": \"italic #00FFFF\",\n    \"error\": \"bold\"\n  }\n}").unwrap();
+        let theme = Theme::load(&json).unwrap();
+        assert_eq!(theme.name(), "night");
+        assert_eq!(theme.get("error"), Some(Style::new().attrs(Attributes::BOLD)));
+
+        std::fs::write(&json, "{\"roles\": {\n\"info\": \"on\"}}").unwrap();
+        match Theme::load(&json) {
+            Err(MakeColorsError::InvalidTheme { line: 2, message, .. }) => assert!(message.contains("'on'"), "{}", message),
+            other => panic!("expected a theme error, got {:?}", other),
+        }
+
+        assert!(matches!(Theme::load(dir.join("missing.toml")), Err(MakeColorsError::ThemeFile { .. })));
+        assert!(matches!(Theme::load(dir.join("theme.yaml")), Err(MakeColorsError::ThemeFile { .. })));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+// File: src\vision.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-17
+// Description: Color vision deficiency simulation and color-blind-safe palettes.
+// License: MIT
+
+//! Color vision deficiencies.
+//!
+//! [`simulate`] shows how a color looks with protanopia, deuteranopia or
+//! tritanopia, using the Machado, Oliveira and Fernandes (2009) model on
+//! linear RGB. [`theme_conflicts`] uses it to find theme roles that can be
+//! told apart with normal vision but collapse into the same color with one
+//! of those deficiencies, such as a red `error` next to a green `success`.
+//!
+//! [`OKABE_ITO`] is the Okabe-Ito palette, designed to stay distinct for
+//! all three, and the built-in `"okabe-ito"` [`Theme`] uses it for the
+//! standard roles.
+//!
+//! # Examples
+//! ```
+//! use make_colors::vision::{self, Deficiency};
+//! use make_colors::{Color, Theme};
+//!
+//! let seen = vision::simulate(Color::Rgb(255, 0, 0), Deficiency::Deuteranopia);
+//! println!("red looks like {:?}", seen);
+//!
+//! for conflict in vision::theme_conflicts(&Theme::default()) {
+//!     println!("{} and {} look alike with {}", conflict.roles.0, conflict.roles.1, conflict.deficiency);
+//! }
+//! assert!(vision::theme_conflicts(&Theme::builtin("okabe-ito").unwrap()).is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::colorspace::{Oklab, Rgb};
+use crate::{Color, Style, Theme};
+
+/// The Okabe-Ito palette: black, orange, sky blue, bluish green, yellow,
+/// blue, vermillion and reddish purple
+pub const OKABE_ITO: [Color; 8] = [
+    Color::Rgb(0, 0, 0),
+    Color::Rgb(230, 159, 0),
+    Color::Rgb(86, 180, 233),
+    Color::Rgb(0, 158, 115),
+    Color::Rgb(240, 228, 66),
+    Color::Rgb(0, 114, 178),
+    Color::Rgb(213, 94, 0),
+    Color::Rgb(204, 121, 167),
+];
+
+/// OKLab distance below which two colors are considered indistinguishable
+pub const MIN_DISTANCE: f32 = 0.08;
+
+/// A kind of dichromacy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// No working red cones
+    Protanopia,
+    /// No working green cones
+    Deuteranopia,
+    /// No working blue cones
+    Tritanopia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 3] = [Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia];
+
+    /// Machado et al. simulation matrix for linear RGB at full severity
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_82, 0.042_94, 0.968_881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_9],
+            ],
+        }
+    }
+}
+
+impl fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+        })
+    }
+}
+
+/// Two roles of a theme that look alike with a deficiency
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub roles: (String, String),
+    pub deficiency: Deficiency,
+    /// OKLab distance of the simulated colors
+    pub distance: f32,
+}
+
+/// How `color` appears with `deficiency`
+///
+/// Named and 256-color values are resolved with the xterm palette, so the
+/// result is a [`Color::Rgb`]; [`Color::Default`] is returned unchanged.
+pub fn simulate(color: Color, deficiency: Deficiency) -> Color {
+    let Some(rgb) = color.convert::<Rgb>() else {
+        return color;
+    };
+    let linear = rgb.to_linear();
+    let simulated = deficiency.matrix().map(|row| row.iter().zip(linear).map(|(m, c)| m * c).sum::<f32>());
+    Rgb::from_linear(simulated).into()
+}
+
+/// Perceptual distance between two colors (Euclidean distance in OKLab)
+///
+/// # Examples
+/// ```
+/// use make_colors::vision::color_distance;
+/// use make_colors::Color;
+///
+/// assert_eq!(color_distance(Color::Red, Color::Red), Some(0.0));
+/// assert!(color_distance(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)).unwrap() > 0.99);
+/// ```
+pub fn color_distance(a: Color, b: Color) -> Option<f32> {
+    let (a, b): (Oklab, Oklab) = (a.convert()?, b.convert()?);
+    Some(((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt())
+}
+
+/// Whether `a` and `b` can still be told apart with `deficiency`
+pub fn distinguishable(a: Color, b: Color, deficiency: Deficiency) -> bool {
+    color_distance(simulate(a, deficiency), simulate(b, deficiency)).is_none_or(|d| d >= MIN_DISTANCE)
+}
+
+/// Pairs of roles in `theme` that look alike with one of the deficiencies
+///
+/// Roles are compared by their foreground and background colors; a side
+/// that neither role sets is equal, and one that only one role sets tells
+/// them apart. Pairs that already look alike with normal vision are not
+/// reported.
+pub fn theme_conflicts(theme: &Theme) -> Vec<Conflict> {
+    let roles: Vec<(&str, Style)> = theme.iter().collect();
+    let mut conflicts = Vec::new();
+
+    for (i, (first, a)) in roles.iter().enumerate() {
+        for (second, b) in &roles[i + 1..] {
+            let distance = |transform: &dyn Fn(Color) -> Color| {
+                let side = |x: Option<Color>, y: Option<Color>| {
+                    match (x.filter(|c| *c != Color::Default), y.filter(|c| *c != Color::Default)) {
+                        (None, None) => Some(0.0),
+                        (Some(x), Some(y)) => color_distance(transform(x), transform(y)),
+                        _ => None,
+                    }
+                };
+                Some(side(a.fg, b.fg)?.max(side(a.bg, b.bg)?))
+            };
+
+            if distance(&|c| c).is_none_or(|d| d < MIN_DISTANCE) {
+                continue;
+            }
+            for deficiency in Deficiency::ALL {
+                match distance(&|c| simulate(c, deficiency)) {
+                    Some(distance) if distance < MIN_DISTANCE => conflicts.push(Conflict {
+                        roles: (first.to_string(), second.to_string()),
+                        deficiency,
+                        distance,
+                    }),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::BUILTIN_THEMES;
+
+    #[test]
+    fn test_simulate() {
+        let (red, green) = (Color::Rgb(200, 30, 30), Color::Rgb(30, 160, 30));
+        assert!(distinguishable(red, green, Deficiency::Tritanopia));
+        assert!(!distinguishable(red, green, Deficiency::Deuteranopia));
+        assert!(!distinguishable(Color::Rgb(0, 158, 115), Color::Rgb(127, 127, 127), Deficiency::Deuteranopia));
+
+        // Grays are seen the same way by everyone
+        for deficiency in Deficiency::ALL {
+            assert_eq!(simulate(Color::Rgb(128, 128, 128), deficiency), Color::Rgb(128, 128, 128));
+            assert_eq!(simulate(Color::Default, deficiency), Color::Default);
+        }
+    }
+
+    #[test]
+    fn test_theme_conflicts() {
+        let theme = Theme::new("status")
+            .with("error", Style::new().fg(Color::Rgb(200, 30, 30)))
+            .with("success", Style::new().fg(Color::Rgb(30, 160, 30)))
+            .with("info", Style::new().fg(Color::Rgb(30, 160, 30)))
+            .with("alert", Style::new().fg(Color::Rgb(200, 30, 30)).bg(Color::Black));
+        let conflicts = theme_conflicts(&theme);
+        let pairs: Vec<_> = conflicts.iter().map(|c| (c.roles.0.as_str(), c.roles.1.as_str(), c.deficiency)).collect();
+        assert_eq!(
+            pairs,
+            [
+                ("error", "info", Deficiency::Deuteranopia),
+                ("error", "success", Deficiency::Deuteranopia),
+            ]
+        );
+
+        assert!(BUILTIN_THEMES.contains(&"okabe-ito"));
+        assert!(theme_conflicts(&Theme::builtin("okabe-ito").unwrap()).is_empty());
+    }
+}